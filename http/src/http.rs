@@ -95,6 +95,30 @@ macro_rules! create_value_type {
         Value::UnSignedNumber16($value)
     };
 
+    (i32, $value:expr) => {
+        Value::SignedNumber32($value)
+    };
+
+    (u8, $value:expr) => {
+        Value::UnSignedNumber8($value)
+    };
+
+    (i8, $value:expr) => {
+        Value::SignedNumber8($value)
+    };
+
+    (f32, $value:expr) => {
+        Value::Float32($value)
+    };
+
+    (f64, $value:expr) => {
+        Value::Float64($value)
+    };
+
+    (bool, $value:expr) => {
+        Value::Bool($value)
+    };
+
     
 
    (Vecu8,  $value:expr) =>{
@@ -129,6 +153,30 @@ macro_rules! get_underlying_type {
         $value.get_unsigned_num_16_value()
     };
 
+    (i32, $value:expr) => {
+        $value.get_signed_num_32_value()
+    };
+
+    (u8, $value:expr) => {
+        $value.get_unsigned_num_8_value()
+    };
+
+    (i8, $value:expr) => {
+        $value.get_signed_num_8_value()
+    };
+
+    (f32, $value:expr) => {
+        $value.get_float_32_value()
+    };
+
+    (f64, $value:expr) => {
+        $value.get_float_64_value()
+    };
+
+    (bool, $value:expr) => {
+        $value.get_bool_value()
+    };
+
     (usize, $value:expr) => {
         $value.get_unsigned_num_value()
     };
@@ -288,7 +336,7 @@ impl ResponseInfo for HttpResponseInfo {
         self.add_info("status_code".to_owned(), Value::String("200".to_string()))?;
         self.add_info("protocol_version".to_owned(), Value::String("HTTP/1.1".to_string()))?;
         self.add_info("status_text".to_owned(), Value::String("OK".to_string()))?;
-        self.add_info("Date".to_owned(), Value::String(now.format("%a %d %b %Y %H %M %S GMT").to_string()))?;
+        self.add_info("Date".to_owned(), Value::String(TimestampFormat::ImfFixdate.format(&now)))?;
         Ok(())
     }
 }
@@ -414,6 +462,26 @@ impl Product{
     }
 }
 
+/// Fields of the request used by product handlers
+#[derive(Deserialize)]
+struct ProductRequest{
+    request_uri: String,
+    #[serde(rename = "Content-Type")]
+    content_type: Option<String>,
+    request_body: Option<String>,
+}
+
+/// Response returned by product handlers
+#[derive(Serialize)]
+struct ProductResponse{
+    status_code: String,
+    status_text: String,
+    protocol_version: String,
+    #[serde(rename = "Content-Type")]
+    content_type: String,
+    response_body: Vec<u8>,
+}
+
 impl HttpRequestHandler{
     
     async fn handle_get(&self,
@@ -423,15 +491,14 @@ impl HttpRequestHandler{
             let get_request_uri = request.get_request_uri().unwrap().to_owned();
             if get_request_uri == "/product/1" {
                 let content = to_string(&Product::new(1, "Table".to_owned()))?.into_bytes();
-                let response = HttpResponseBuilder(response)
-                    .with_status_code("200".to_owned())
-                    .with_status_text("OK".to_owned())
-                    .with_protocol_version("HTTP/1.1".to_owned())
-                    .with_content_type("application/json".to_owned())
-                    .with_content_length(content.len().to_string())
-                    .with_response_body(content)
-                    .build();
-                return Ok(response);
+                to_info_provider(&ProductResponse{
+                    status_code: "200".to_owned(),
+                    status_text: "OK".to_owned(),
+                    protocol_version: "HTTP/1.1".to_owned(),
+                    content_type: "application/json".to_owned(),
+                    response_body: content,
+                }, response)?;
+                return Ok(std::mem::take(response));
             }
            Ok(Default::default())
 
@@ -440,12 +507,11 @@ impl HttpRequestHandler{
     async fn handle_post(&self,
         request: &HttpRequestInfo,
         response: &mut HttpResponseInfo,) -> Result<HttpResponseInfo, ParserError> {
-            
-            if request.get_request_uri().unwrap().as_str() == "/product/1" {
-                let body = request.get_request_body().unwrap();
-                let content = from_utf8(body)?;
-                if request.get_content_type().unwrap_or("application/json".to_owned()) == "application/json" {
-                    let product: Product = serde_json::from_str(content).unwrap();
+            let request: ProductRequest = from_info_provider(request)?;
+            if request.request_uri == "/product/1" {
+                let content = request.request_body.unwrap_or_default();
+                if request.content_type.as_deref().unwrap_or("application/json") == "application/json" {
+                    let product: Product = serde_json::from_str(&content)?;
                     let response = HttpResponseBuilder(response)
                     .with_status_code("201".to_owned())
                     .with_status_text("Created".to_owned())                    
@@ -474,7 +540,7 @@ impl HttpRequestHandler{
                 let body = request.get_request_body().unwrap();
                 let content = from_utf8(body)?;
                 if request.get_content_type().unwrap_or("application/json".to_owned()) == "application/json" {
-                    let product: Product = serde_json::from_str(content).unwrap();
+                    let product: Product = serde_json::from_str(content)?;
                     let response = HttpResponseBuilder(response)
                     .with_status_code("200".to_owned())
                     .with_status_text("OK".to_owned())                    
@@ -740,81 +806,11 @@ pub fn build_http_request_protocol() -> ListSpec {
     let spec_builder = spec_builder.expect_composite(request_line_placeholder)
     .repeat_many(Name("headers".to_owned()), true, Separator::Delimiter("\r\n".to_owned()),header_place_holder)
     
-    .use_spec(Box::new(BodySpec::new(Name("request_body".to_owned()), true)));
+    .expect_bytes_with_length_from(Name("request_body".to_owned()), "Content-Length".to_owned(), LengthEncoding::Decimal, true);
 
     spec_builder.build()
 }
 
-pub struct BodySpec{
-    spec_meta_data: SpecMetaData,
-}
-
-impl BodySpec{
-    pub fn new(name: SpecName, optional: bool) -> Self{        
-        let spec_meta_data = SpecMetaData::new(name, ValueType::None, optional);
-        BodySpec { spec_meta_data }
-    }
-}
-
-impl SimpleValueSpec for BodySpec{}
-
-
-//impl ToSpecType for BodySpec{}
-
-/* impl SpecTraverse for BodySpec{
-    fn traverse(&self, mapper: &mut Box<dyn Mapper>) {
-        traverse_spec(self, mapper);
-    }
-} */
-
-#[async_trait]
-impl SpecDeserialize for BodySpec{
-    async fn deserialize (
-        &self,
-        info_provider: &mut ( dyn InfoProvider + Send + Sync ),
-        reader: &mut dyn SpecRead, update_info: bool,
-    ) -> Result<Value, ParserError>{
-        let content_length_option = info_provider.get_info("Content-Length");
-        return match content_length_option{
-            Some(value) => {
-                let content_length_str = value.get_string_value();
-                let content_length: u32 = content_length_str.unwrap().parse().expect("Failed to parse string to u16 for content-length header");
-                NBytesSpec::new(self.spec_meta_data.get_name().clone(), content_length, self.spec_meta_data.is_optional()).deserialize(info_provider, reader, update_info).await
-            },
-            None => {
-                Ok(Value::None)
-            },
-        }
-    }
-}
-
-impl Spec for BodySpec{
-    fn get_meta_data(&self) -> &SpecMetaData {
-        &self.spec_meta_data
-    }
-}
-
-#[async_trait]
-impl SpecSerialize for BodySpec{
-
-    async fn serialize (
-            &self,
-            info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
-            writer: &mut dyn SpecWrite
-        ) -> Result<(), ParserError>{
-            //mapper_context.start_spec(self);
-            let spec_name = mapper_context.get_last_available_spec_name();
-            if let Some(spec_name) = spec_name{
-                let bytes = info_provider.get_info(&spec_name);
-                if let Some(value) = bytes{
-                    return writer.write_data_bytes(value.get_u8_vec().unwrap()).await;
-                }
-            }
-            Ok(())
-        }
-    
-}
-
 //use crate::core::SpecName::*;
 #[allow(unused)]
 pub fn build_http_response_protocol() -> ListSpec {
@@ -846,7 +842,7 @@ pub fn build_http_response_protocol() -> ListSpec {
 
     let root_builder = root_builder.expect_composite(response_line_placeholder)
     .repeat_many( Name("headers".to_owned()), true,Separator::Delimiter("\r\n".to_owned()), header_place_holder, )    
-    .use_spec(Box::new(BodySpec::new(Name("response_body".to_owned()), true)));
+    .expect_bytes_with_length_from(Name("response_body".to_owned()), "Content-Length".to_owned(), LengthEncoding::Decimal, true);
     root_builder.build()
 }
 
@@ -868,6 +864,45 @@ fn build_http_request_info() -> HttpRequestInfo {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_response_has_content_length() {
+        let request_spec = build_http_request_protocol();
+        let mut request = HttpRequestInfo::default();
+        assert!(request_spec.traverse(request.get_mapper_mut()).is_ok());
+        request.set_request_method("GET".to_owned());
+        request.set_request_uri("/product/1".to_owned());
+
+        let response_spec = build_http_response_protocol();
+        let mut response = HttpResponseInfo::default();
+        assert!(response_spec.traverse(response.get_mapper_mut()).is_ok());
+        let response = HttpRequestHandler.handle_get(&request, &mut response).await;
+        assert!(response.is_ok(), "expected success, but got error {:?}", response.as_ref().err());
+
+        // Content-Length header is computed from the body during serialization
+        let mut buffer = Vec::new();
+        let result = ResponseSerializer::serialize_to(&DefaultSerializer{}, response.unwrap(), &mut buffer, &response_spec).await;
+        assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+        let body = to_string(&Product::new(1, "Table".to_owned())).unwrap();
+        let response = String::from_utf8(buffer).unwrap();
+        assert!(response.contains(&format!("Content-Length: {}\r\n", body.len())), "Content-Length missing in {}", response);
+        assert!(response.ends_with(&format!("\r\n\r\n{}", body)), "body missing in {}", response);
+    }
+
+    #[tokio::test]
+    async fn test_post_with_invalid_json_fails() {
+        let request_spec = build_http_request_protocol();
+        let mut request = HttpRequestInfo::default();
+        assert!(request_spec.traverse(request.get_mapper_mut()).is_ok());
+        request.set_request_method("POST".to_owned());
+        request.set_request_uri("/product/1".to_owned());
+        request.set_request_body(b"{not json".to_vec());
+
+        let mut response = HttpResponseInfo::default();
+        let response = HttpRequestHandler.handle_post(&request, &mut response).await;
+        assert!(matches!(response, Err(ParserError::SerdeError(_))), "expected serde error, but got {:?}", response.err());
+    }
 
     #[tokio::test]
     async fn test_parsing_get_request() {
@@ -56,6 +56,14 @@ pub trait PlaceHolderRead {
         
     ) -> Result<Option<Vec<u8>>, ParserError>;
 
+    /// Reads `input` ignoring ascii case. Returns the bytes as they appear in the stream
+    #[allow(unused)]
+    async fn read_placeholder_as_string_ignore_case(
+        & mut self,
+        input: String,
+        
+    ) -> Result<Option<Vec<u8>>, ParserError>;
+
     #[allow(unused)]
     async fn read_placeholder_until(
         &mut self,        
@@ -63,6 +71,12 @@ pub trait PlaceHolderRead {
         
     ) -> Result<Option<Vec<u8>>, ParserError>;
 
+    /// Reads until the earliest of the `delimiters`. Returns the bytes read along with the index of the matched delimiter
+    async fn read_placeholder_until_any(
+        &mut self,
+        delimiters: Vec<String>,
+    ) -> Result<Option<(Vec<u8>, usize)>, ParserError>;
+
 
     #[allow(unused)]
     async fn read_bytes(
@@ -70,6 +84,14 @@ pub trait PlaceHolderRead {
         size: ReadBytesSize,
         
     ) -> Result<Option<Vec<u8>>, ParserError>;
+
+    /// Reads till the unescaped `quote` after the opening quote is read. Bytes preceded by `escape` are unescaped and a 
+    /// quote is escaped by doubling it if `escape` is the `quote`. Returns None if the stream ends before the closing quote
+    async fn read_quoted(
+        &mut self,
+        quote: u8,
+        escape: u8,
+    ) -> Result<Option<Vec<u8>>, ParserError>;
 }
 
 
@@ -79,6 +101,12 @@ pub trait MarkAndRead:AsyncBufRead + Unpin + Send + Sync {
     fn unmark(&mut self, mark: &Marker) -> Result<(), ParserError>;
     fn is_valid_marker(&self, marker: &Marker) -> Result<(), ParserError>;
     fn has_markers(&self) -> bool;
+
+    /// Returns the bytes read after `mark`. The marker should be the last marker
+    fn marked_bytes(&self, mark: &Marker) -> Result<Vec<u8>, ParserError>;
+
+    /// Returns the absolute offset of the next byte to be read from the start of the stream
+    fn offset(&self) -> usize;
 }
 
 
@@ -114,6 +142,7 @@ where
     line_index: usize,
     char_index: usize,
     char_index_in_line: usize,
+    drained: usize,
 }
 
 impl <R> SpecRead for ProtocolBuffReader<R>
@@ -213,6 +242,15 @@ impl <B> MarkAndRead for ProtocolBuffReader<B>
     fn has_markers(&self) -> bool {
         !self.markers.is_empty()
     }
+
+    fn marked_bytes(&self, marker: &Marker) -> Result<Vec<u8>, ParserError> {
+        self.is_valid_marker(marker)?;
+        Ok(self.buf[marker.pos..self.pos].to_vec())
+    }
+
+    fn offset(&self) -> usize {
+        self.drained + self.pos
+    }
 }
 
 
@@ -290,8 +328,9 @@ impl <R>ProtocolBuffReader<R>
     }
     
     fn consume_and_drain(&mut self, amount: usize) {
-        if self.pos >= self.buf.len() / 2 && !self.marked {
+        if self.pos >= self.buf.len() / 2 && !self.marked && self.markers.is_empty() {
             self.buf.drain(0..self.pos + amount);
+            self.drained += self.pos + amount;
             self.pos = 0;
         } else {
             self.pos += amount;
@@ -304,7 +343,7 @@ impl <R>ProtocolBuffReader<R>
     }
 
     fn buf_has_enough_data(&self, bytes_size: &ReadBytesSize) -> bool {
-        (self.buf.len() - self.pos + 1) as u32 >= bytes_size.get_value()
+        (self.buf.len() - self.pos) as u32 >= bytes_size.get_value()
     }
 
     #[allow(unused)]
@@ -320,6 +359,7 @@ impl <R>ProtocolBuffReader<R>
             line_index: 0,
             char_index: 0,
             char_index_in_line: 0,
+            drained: 0,
         }
     }
 
@@ -389,7 +429,6 @@ impl ReadBytesSize {
         }
     }
 
-    #[allow(unused)]
     fn is_full(&self) -> bool {
         matches!(self, ReadBytesSize::Full)
     }
@@ -473,7 +512,10 @@ where
                         }
                     }
                     Poll::Ready(Err(e)) => {
-                        return Poll::Ready(Err(convert_io_error(e)));
+                        // end of stream completes the read of remaining bytes
+                        if !(read_bytes_expected_size.is_full() && e.kind() == ErrorKind::UnexpectedEof) {
+                            return Poll::Ready(Err(convert_io_error(e)));
+                        }
                     }
                 };
             }
@@ -481,8 +523,11 @@ where
             let pos = protocol_reader.pos;
             if let ReadBytesSize::Fixed(size) = read_bytes_expected_size {
                 let size = *size as usize;
-                if pos + size -1 < buf.len() {
-                    return Poll::Ready(Ok(Some(buf[pos..pos + size].to_vec())));
+                if pos + size <= buf.len() {
+                    let data = buf[pos..pos + size].to_vec();
+                    protocol_reader.consume_and_drain(size);
+                    protocol_reader.increment_char_index_by(size);
+                    return Poll::Ready(Ok(Some(data)));
                 } else {
                     return Poll::Ready(Err(ParserError::TokenExpected {
                         line_index: protocol_reader.line_index,
@@ -492,9 +537,10 @@ where
                 }
 
             }else {
-                return Poll::Ready(Ok(Some(
-                        buf[pos..buf.len()].to_vec())
-                ));
+                let data = buf[pos..buf.len()].to_vec();
+                protocol_reader.consume_and_drain(data.len());
+                protocol_reader.increment_char_index_by(data.len());
+                return Poll::Ready(Ok(Some(data)));
             }            
         }
     }
@@ -507,7 +553,7 @@ where
 {
     protocol_reader: &'a mut ProtocolBuffReader<R>,
     //placeholder: &'a Placeholder,
-    delimiter: String,    
+    delimiters: Vec<String>,    
 }
 
 #[pin_project]
@@ -517,6 +563,7 @@ where
 {
     protocol_reader: &'a mut ProtocolBuffReader<R>,
     input: String,
+    ignore_case: bool,
 }
 
 impl <'a, R> ReadPlaceHolderUntil<'a, R> where
@@ -524,11 +571,11 @@ R: AsyncBufRead + Send + Sync + Unpin,
 {
     fn new(
         protocol_reader: &'a mut ProtocolBuffReader<R>,
-        delimiter: String,        
+        delimiters: Vec<String>,        
     ) -> Self {
         ReadPlaceHolderUntil {
             protocol_reader,
-            delimiter,            
+            delimiters,            
         }
     }
 }
@@ -536,23 +583,23 @@ R: AsyncBufRead + Send + Sync + Unpin,
 impl<R> Future for ReadPlaceHolderUntil<'_, R>
 where
     R: AsyncBufRead + Send + Sync + Unpin,{
-    type Output = Result<Option<Vec<u8>>, ParserError>;
+    type Output = Result<Option<(Vec<u8>, usize)>, ParserError>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
         let mut this = self.project();
         //let placeholder = this.placeholder;
-        let delimiter = this.delimiter;
+        let delimiters = this.delimiters;
         let protocol_reader = &mut this.protocol_reader;
         //if protocol_reader.pos < (protocol_reader.cap - 1) {
             //protocol_reader.mark_if_optional(placeholder);
             //let pinned_reader = Pin::new(&mut protocol_reader.inner);
-            if let Some(value) = perform_search(cx, delimiter, protocol_reader) {
+            if let Some(value) = perform_search(cx, delimiters, protocol_reader) {
                 match value {
                     Poll::Ready(result) => match result {
-                        Ok(index) => {
+                        Ok((index, delimiter_index)) => {
                             let matched_portion =
                                 protocol_reader.get_buffer()[protocol_reader.pos..index].to_vec();
-                            protocol_reader.consume_and_drain(matched_portion.len() + delimiter.len());                                
+                            protocol_reader.consume_and_drain(matched_portion.len() + delimiters[delimiter_index].len());                                
                             /* let place_holder_value = PlaceHolderValue::parse(
                                 &placeholder.place_holder_type,
                                 matched_portion,
@@ -560,7 +607,7 @@ where
 
                             
                             //protocol_reader.unmark_if_optional(placeholder);
-                            return Poll::Ready(Ok(Some(matched_portion)));
+                            return Poll::Ready(Ok(Some((matched_portion, delimiter_index))));
                         }
                         Err(e) => {
                             //protocol_reader.reset_if_optional(placeholder);
@@ -595,11 +642,13 @@ where
         protocol_reader: &'a mut ProtocolBuffReader<R>,
         
         input: String,
+        ignore_case: bool,
     ) -> Self {
         ReadString {
             protocol_reader,
         
             input,
+            ignore_case,
         }
     }
 }
@@ -615,11 +664,12 @@ where
 
         //let placeholder = this.placeholder;
         let input = this.input;
+        let ignore_case = *this.ignore_case;
         let protocol_reader = &mut this.protocol_reader;
         //if !protocol_reader.buf_has_enough_data(input.len()) {
         //let pinned_reader = Pin::new(&mut protocol_reader.inner);
         //protocol_reader.mark_if_optional(placeholder);
-        if let Some(value) = read_string(cx, input, protocol_reader) {
+        if let Some(value) = read_string(cx, input, ignore_case, protocol_reader) {
             match value {
                 Poll::Ready(result) => match result {
                     Ok(index) => {
@@ -656,6 +706,94 @@ where
     }
 }
 
+#[pin_project]
+struct ReadQuoted<'a, R>
+where
+    R: AsyncBufRead + Send + Sync + Unpin,
+{
+    protocol_reader: &'a mut ProtocolBuffReader<R>,
+    quote: u8,
+    escape: u8,
+}
+
+impl<'a, R> ReadQuoted<'a, R>
+where
+    R: AsyncBufRead + Send + Sync + Unpin,
+{
+    fn new(protocol_reader: &'a mut ProtocolBuffReader<R>, quote: u8, escape: u8) -> Self {
+        ReadQuoted {
+            protocol_reader,
+            quote,
+            escape,
+        }
+    }
+}
+
+impl<R> Future for ReadQuoted<'_, R>
+where
+    R: AsyncBufRead + Send + Sync + Unpin,
+{
+    type Output = Result<Option<Vec<u8>>, ParserError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let protocol_reader = this.protocol_reader;
+        let mut end_of_stream = false;
+        loop {
+            let buffer = protocol_reader.get_current_buffer();
+            if let Some(result) = scan_quoted(buffer, *this.quote, *this.escape, end_of_stream) {
+                if let Some((consumed, _)) = &result {
+                    protocol_reader.consume_and_drain(*consumed);
+                    protocol_reader.increment_char_index_by(*consumed);
+                }
+                return Poll::Ready(Ok(result.map(|(_, unescaped)| unescaped)));
+            }
+            match protocol_reader.fill_buffer(cx) {
+                Poll::Ready(Ok(read_length)) if read_length > 0 => continue,
+                Poll::Ready(Ok(_)) => end_of_stream = true,
+                Poll::Ready(Err(e)) if e.kind() == ErrorKind::UnexpectedEof => end_of_stream = true,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(convert_io_error(e))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Scans `buffer` for the closing quote and returns the number of bytes to consume along with the unescaped bytes.
+/// Returns None if more data is needed to find the end of the string and Some(None) if the string is not terminated
+/// at the end of the stream
+fn scan_quoted(buffer: &[u8], quote: u8, escape: u8, end_of_stream: bool) -> Option<Option<(usize, Vec<u8>)>> {
+    let mut unescaped = Vec::new();
+    let mut index = 0;
+    while index < buffer.len() {
+        let byte = buffer[index];
+        let next = buffer.get(index + 1).copied();
+        if byte == quote && escape == quote {
+            // quote is escaped by doubling it e.g 'o''brien', hence a single quote ends the string
+            match next {
+                Some(next) if next == quote => unescaped.push(next),
+                Some(_) => return Some(Some((index + 1, unescaped))),
+                None if end_of_stream => return Some(Some((index + 1, unescaped))),
+                None => return None,
+            }
+            index += 2;
+        } else if byte == escape {
+            match next {
+                Some(next) => unescaped.push(next),
+                None if end_of_stream => return Some(None),
+                None => return None,
+            }
+            index += 2;
+        } else if byte == quote {
+            return Some(Some((index + 1, unescaped)));
+        } else {
+            unescaped.push(byte);
+            index += 1;
+        }
+    }
+    if end_of_stream { Some(None) } else { None }
+}
+
 #[allow(unused)]
 fn token_expected_error(line_index:usize, line_char_pos:usize) -> ParserError {
     ParserError::TokenExpected {
@@ -665,22 +803,41 @@ fn token_expected_error(line_index:usize, line_char_pos:usize) -> ParserError {
     }
 }
 
+/// Searches for the earliest of the `delimiters` and returns the index of the match along with the index of the matched delimiter.
+/// Longest delimiter is preferred when more than one delimiter match at the same index e.g "\r\n" over "\r"
 fn perform_search<R>(
     cx: &mut Context<'_>,
-    delimiter: &mut String,
+    delimiters: &[String],
     protocol_reader: &mut ProtocolBuffReader<R>,
-) -> Option<Poll<Result<usize, ParserError>>>
+) -> Option<Poll<Result<(usize, usize), ParserError>>>
 where
     R: AsyncBufRead + Send + Sync + Unpin,
 {
-    let finder = Finder::new(delimiter.as_bytes());
+    let finders: Vec<Finder> = delimiters.iter().map(|delimiter| Finder::new(delimiter.as_bytes())).collect();
     loop {
-        let result = finder.find(protocol_reader.get_current_buffer());
+        let buffer = protocol_reader.get_current_buffer();
+        let result = finders.iter().enumerate()
+            .filter_map(|(delimiter_index, finder)| finder.find(buffer).map(|match_index| (match_index, delimiter_index)))
+            .min_by_key(|(match_index, delimiter_index)| (*match_index, std::cmp::Reverse(delimiters[*delimiter_index].len())));
 
         match result {
-            Some(match_index) => {
+            Some((match_index, delimiter_index)) => {
+                // longer delimiter starting at the same index may not be fully available in the buffer yet
+                let remaining = &buffer[match_index..];
+                let incomplete = delimiters.iter()
+                    .any(|delimiter| delimiter.len() > remaining.len() && delimiter.as_bytes().starts_with(remaining));
+                if incomplete {
+                    match protocol_reader.fill_buffer(cx) {
+                        Poll::Ready(Ok(read_length)) if read_length > 0 => continue,
+                        Poll::Pending => return Some(Poll::Pending),
+                        // end of stream, the match found so far is the delimiter
+                        Poll::Ready(Ok(_)) => {}
+                        Poll::Ready(Err(e)) if e.kind() == ErrorKind::UnexpectedEof => {}
+                        Poll::Ready(Err(e)) => return Some(Poll::Ready(Err(ParserError::IOError { error: e }))),
+                    }
+                }
                 //protocol_reader.pos = index + 1;
-                return Some(Poll::Ready(Ok(match_index + protocol_reader.pos)));
+                return Some(Poll::Ready(Ok((match_index + protocol_reader.pos, delimiter_index))));
             }
 
             None => {
@@ -710,6 +867,7 @@ where
 fn read_string<R>(
     cx: &mut Context<'_>,
     input: &mut String,
+    ignore_case: bool,
     protocol_reader: &mut ProtocolBuffReader<R>,
 ) -> Option<Poll<Result<usize, ParserError>>>
 where
@@ -738,7 +896,13 @@ where
         debug!("pos is {}", pos);
         debug!("input get bytes {:?}, len {}", input.as_bytes(), input.len());
         debug!("buf  get byets {:?}, len {}", &buf[pos..pos + input.len()], &buf[pos..pos + input.len()].len());
-        if &buf[pos..pos + input.len()] == input.as_bytes() {
+        let candidate = &buf[pos..pos + input.len()];
+        let matched = if ignore_case {
+            candidate.eq_ignore_ascii_case(input.as_bytes())
+        } else {
+            candidate == input.as_bytes()
+        };
+        if matched {
             return Some(Poll::Ready(Ok(pos)));
         } else {
             return Some(Poll::Ready(Err(ParserError::TokenExpected {
@@ -760,7 +924,19 @@ where
         delimiter: String,        
         
     ) -> Result<Option<Vec<u8>>, ParserError>{
-        let data = timeout(Duration::from_millis(300), ReadPlaceHolderUntil::new(self, delimiter)).await;
+        let data = timeout(Duration::from_millis(300), ReadPlaceHolderUntil::new(self, vec![delimiter])).await;
+        match data {
+            Ok(Ok(data)) => Ok(data.map(|(data, _)| data)),
+            Ok(Err(e)) => Err(e),
+            Err(_) => Err(ParserError::EndOfStream),
+        }
+    }
+
+    async fn read_placeholder_until_any(
+        self: &mut Self,
+        delimiters: Vec<String>,
+    ) -> Result<Option<(Vec<u8>, usize)>, ParserError>{
+        let data = timeout(Duration::from_millis(300), ReadPlaceHolderUntil::new(self, delimiters)).await;
         match data {
             Ok(Ok(data)) => Ok(data),
             Ok(Err(e)) => Err(e),
@@ -773,7 +949,20 @@ where
         input: String,
     ) -> Result<Option<Vec<u8>>, ParserError>
     {
-        let data = timeout(Duration::from_millis(300),ReadString::new(self, input)).await;
+        let data = timeout(Duration::from_millis(300),ReadString::new(self, input, false)).await;
+        match data {
+            Ok(Ok(data)) => Ok(data),
+            Ok(Err(e)) => Err(e),
+            Err(_e) => Err(ParserError::EndOfStream),
+        }
+    }
+
+    async fn read_placeholder_as_string_ignore_case(
+        self: &mut Self,
+        input: String,
+    ) -> Result<Option<Vec<u8>>, ParserError>
+    {
+        let data = timeout(Duration::from_millis(300),ReadString::new(self, input, true)).await;
         match data {
             Ok(Ok(data)) => Ok(data),
             Ok(Err(e)) => Err(e),
@@ -793,6 +982,20 @@ where
                 Err(_e) => Err(ParserError::EndOfStream),
             }
     }
+
+    async fn read_quoted(
+        self: &mut Self,
+        quote: u8,
+        escape: u8,
+    ) -> Result<Option<Vec<u8>>, ParserError>
+    {
+        let data = timeout(Duration::from_millis(300), ReadQuoted::new(self, quote, escape)).await;
+        match data {
+            Ok(Ok(data)) => Ok(data),
+            Ok(Err(e)) => Err(e),
+            Err(_e) => Err(ParserError::EndOfStream),
+        }
+    }
 }
 
 #[allow(unused)]
@@ -858,7 +1061,7 @@ mod tests {
     
 
     
-    use super::{PlaceHolderRead, ProtocolBuffReader};
+    use super::{MarkAndRead, PlaceHolderRead, ProtocolBuffReader, ReadBytesSize};
     
 
     #[tokio::test]
@@ -1248,4 +1451,41 @@ mod tests {
             assert!(second_word.is_none());
         }
     }
+
+    #[tokio::test]
+    async fn test_read_bytes_consumes_data() {
+        let data = b"abcd";
+        let mut protocol_reader = ProtocolBuffReader::new(BufReader::new(&data[..]), 1024);
+        let result = protocol_reader.read_bytes(ReadBytesSize::Fixed(2)).await;
+        assert_eq!(result.unwrap(), Some(b"ab".to_vec()));
+        let result = protocol_reader.read_bytes(ReadBytesSize::Fixed(2)).await;
+        assert_eq!(result.unwrap(), Some(b"cd".to_vec()));
+        let result = protocol_reader.read_bytes(ReadBytesSize::Fixed(1)).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_read_bytes_fills_buffer_when_one_byte_short() {
+        let data = b"abc";
+        // The inner reader hands out two bytes at a time, so the first fill leaves the
+        // buffer exactly one byte short of the requested size.
+        let mut protocol_reader = ProtocolBuffReader::new(BufReader::with_capacity(2, &data[..]), 1024);
+        let result = protocol_reader.read_bytes(ReadBytesSize::Fixed(3)).await;
+        assert_eq!(result.unwrap(), Some(b"abc".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn test_reset_after_reading_past_half_of_buffer() {
+        let data = b"abcdef";
+        let mut protocol_reader = ProtocolBuffReader::new(BufReader::new(&data[..]), 1024);
+        let marker = protocol_reader.mark();
+        let result = protocol_reader.read_bytes(ReadBytesSize::Fixed(4)).await;
+        assert_eq!(result.unwrap(), Some(b"abcd".to_vec()));
+        // the read position is now past half of the buffer, it must not be drained while marked
+        let result = protocol_reader.read_bytes(ReadBytesSize::Fixed(1)).await;
+        assert_eq!(result.unwrap(), Some(b"e".to_vec()));
+        assert!(protocol_reader.reset(&marker).is_ok());
+        let result = protocol_reader.read_bytes(ReadBytesSize::Fixed(6)).await;
+        assert_eq!(result.unwrap(), Some(b"abcdef".to_vec()));
+    }
 }
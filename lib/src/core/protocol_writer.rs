@@ -16,6 +16,7 @@ where
 {
     #[pin]
     inner: R,
+    offset: usize,
 }
 
 impl <R> ProtocolBuffWriter<R>
@@ -24,7 +25,12 @@ where
 {
     #[allow(unused)]
     pub(super) fn new(inner: R) -> Self {
-        ProtocolBuffWriter { inner }
+        ProtocolBuffWriter { inner, offset: 0 }
+    }
+
+    /// Creates writer whose offset starts at `offset` e.g buffer holding part of a larger stream
+    pub(super) fn with_offset(inner: R, offset: usize) -> Self {
+        ProtocolBuffWriter { inner, offset }
     }
 }
 
@@ -39,7 +45,11 @@ where
     ) -> Poll<Result<usize, io::Error>> {
         let mut pinned_self = self.project();
         let pinned_writer = Pin::new(&mut pinned_self.inner);
-        pinned_writer.poll_write(cx, buf)
+        let result = pinned_writer.poll_write(cx, buf);
+        if let Poll::Ready(Ok(written)) = result {
+            *pinned_self.offset += written;
+        }
+        result
     }
 
     fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
@@ -57,6 +67,9 @@ where
 
 #[async_trait]
 pub trait PlaceHolderWrite  where Self: AsyncWrite  + Unpin{
+    /// Returns the number of bytes written from the start of the stream
+    fn offset(&self) -> usize;
+
      async fn write_string(&mut self, data: String) -> Result<(), ParserError>{
         self.write_all(data.as_bytes()).await?;
         Ok(())
@@ -112,7 +125,11 @@ pub trait PlaceHolderWrite  where Self: AsyncWrite  + Unpin{
 #[async_trait]
 impl<R> PlaceHolderWrite for ProtocolBuffWriter<R>
 where
-    R: AsyncWrite + Unpin{}
+    R: AsyncWrite + Unpin{
+    fn offset(&self) -> usize {
+        self.offset
+    }
+}
 
 impl<R> SpecWrite for ProtocolBuffWriter<R>
 where
@@ -0,0 +1,763 @@
+use std::{collections::HashMap, fmt::Display};
+
+use serde::{
+    de::{self, value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer}, DeserializeOwned, IntoDeserializer, Visitor},
+    forward_to_deserialize_any,
+    ser::{self, Serialize},
+    Deserializer, Serializer,
+};
+
+use crate::core::{InfoProvider, Mapper, ParserError, Value, ValueExtractor};
+
+impl de::Error for ParserError {
+    fn custom<T: Display>(message: T) -> Self {
+        ParserError::SerdeError(message.to_string())
+    }
+}
+
+impl ser::Error for ParserError {
+    fn custom<T: Display>(message: T) -> Self {
+        ParserError::SerdeError(message.to_string())
+    }
+}
+
+/// Deserializes `T` from the data of the InfoProvider. Spec names are used as field names, entries of RepeatMany specs
+/// are deserialized as sequence and key-value pairs of RepeatMany specs as map e.g http headers. Fields of the top level struct
+/// that are not available in the mapper data are looked up using `InfoProvider::get_info`
+pub fn from_info_provider<T, I>(info_provider: &I) -> Result<T, ParserError>
+where T: DeserializeOwned,
+      I: InfoProvider + ?Sized,
+{
+    let tree = mapper_to_tree(info_provider.get_mapper())?;
+    T::deserialize(InfoProviderDeserializer{ info_provider, tree })
+}
+
+/// Serializes `value` into the InfoProvider. Fields are added using spec names, sequences are added as entries of
+/// RepeatMany specs and maps as key-value pairs of RepeatMany specs. Top level fields are added using `InfoProvider::add_info`
+pub fn to_info_provider<T, I>(value: &T, info_provider: &mut I) -> Result<(), ParserError>
+where T: Serialize + ?Sized,
+      I: InfoProvider + ?Sized,
+{
+    let Value::Map(entries) = to_value(value)? else {
+        return Err(ParserError::SerdeError("only structs and maps can be added to info provider".to_owned()));
+    };
+    let spec_names = SpecNames::new(info_provider.get_mapper());
+    add_entries(info_provider, &spec_names, entries, &[])
+}
+
+/// Deserializes `T` from value e.g value returned by composite spec
+pub fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, ParserError> {
+    T::deserialize(value)
+}
+
+/// Serializes `value` into Value. Structs and maps are serialized as `Value::Map` and sequences as `Value::List`
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<Value, ParserError> {
+    value.serialize(ValueSerializer)
+}
+
+/// Names of the specs available in the mapping templates of mapper
+struct SpecNames{
+    /// name and template of specs holding simple values
+    simple: Vec<(String, String)>,
+
+    /// key spec name, value spec name and key template of key-value pairs
+    key_values: Vec<(String, String, String)>,
+}
+
+impl SpecNames{
+    fn new(mapper: &dyn Mapper) -> Self{
+        let templates = mapper.get_mapping_data_template();
+        let named = || templates.iter().filter(|(name, _)| !name.starts_with('$'));
+        // key template is mapped to value template e.g $.headers.{}.header_name -> $.headers.{}.header_value
+        let key_values: Vec<(String, String, String)> = named()
+            .filter_map(|(key_name, key_template)| {
+                let value_template = templates.get(key_template)?;
+                let (value_name, _) = named().find(|(_, template)| *template == value_template)?;
+                Some((key_name.clone(), value_name.clone(), key_template.clone()))
+            })
+            .collect();
+        let simple = named()
+            .filter(|(name, _)| !key_values.iter().any(|(key_name, value_name, _)| key_name == *name || value_name == *name))
+            .map(|(name, template)| (name.clone(), template.clone()))
+            .collect();
+        SpecNames{ simple, key_values }
+    }
+
+    fn is_repeat(&self, name: &str) -> bool{
+        self.simple.iter().map(|(_, template)| template)
+            .chain(self.key_values.iter().map(|(_, _, template)| template))
+            .any(|template| repeat_names(template).contains(&name))
+    }
+
+    /// Key and value spec names of key-value pairs whose innermost RepeatMany spec is `name`
+    fn key_value_of(&self, name: &str) -> Option<(&String, &String)>{
+        self.key_values.iter()
+            .find(|(_, _, template)| repeat_names(template).last() == Some(&name))
+            .map(|(key_name, value_name, _)| (key_name, value_name))
+    }
+}
+
+/// Names of RepeatMany specs in the template, outermost first e.g `parts` and `headers` for `$.parts.{}.headers.{}.header_name`
+fn repeat_names(template: &str) -> Vec<&str>{
+    let segments: Vec<&str> = template.split('.').collect();
+    segments.windows(2).filter(|pair| pair[1] == "{}").map(|pair| pair[0]).collect()
+}
+
+/// Returns the repeat indexes if `path` is an instance of `template`
+fn match_template(template: &str, path: &str) -> Option<Vec<u32>>{
+    let mut template_segments = template.split('.');
+    let mut path_segments = path.split('.');
+    let mut indexes = vec![];
+    loop {
+        match (template_segments.next(), path_segments.next()) {
+            (None, None) => return Some(indexes),
+            (Some("{}"), Some(segment)) => indexes.push(segment.parse().ok()?),
+            (Some(expected), Some(segment)) if expected == segment => {},
+            _ => return None,
+        }
+    }
+}
+
+/// Shape of the template or path with repeat indexes replaced by placeholder e.g `$.headers.{}.header_name` for 
+/// `$.headers.0.header_name`. Paths can only be instances of templates with the same shape
+fn shape_of(path: &str) -> String{
+    path.split('.')
+        .map(|segment| if segment.parse::<u32>().is_ok() { "{}" } else { segment })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Groups `items` by the shape of their template, so a path is matched only against the templates of its shape
+fn by_shape<'a, T>(items: impl Iterator<Item = (&'a String, T)>) -> HashMap<String, Vec<(&'a String, T)>>{
+    let mut shapes: HashMap<String, Vec<(&'a String, T)>> = HashMap::new();
+    for (template, item) in items {
+        shapes.entry(shape_of(template)).or_default().push((template, item));
+    }
+    shapes
+}
+
+/// Finds the template of `path` among `shapes` and returns the item of the template along with the repeat indexes of the path
+fn find_template<'a, T>(shapes: &'a HashMap<String, Vec<(&String, T)>>, path: &str) -> Option<(&'a T, Vec<u32>)>{
+    shapes.get(&shape_of(path))?.iter()
+        .find_map(|(template, item)| match_template(template, path).map(|indexes| (item, indexes)))
+}
+
+/// Step of the path of a value in the tree
+enum TreeStep<'a>{
+    Field(&'a str),
+    Index(u32),
+}
+
+fn repeat_steps<'a>(repeats: &[&'a str], indexes: &[u32]) -> Vec<TreeStep<'a>>{
+    repeats.iter().zip(indexes)
+        .flat_map(|(name, index)| [TreeStep::Field(name), TreeStep::Index(*index)])
+        .collect()
+}
+
+fn insert_at(tree: &mut Value, steps: &[TreeStep], value: Value) -> Result<(), ParserError>{
+    let Some((step, steps)) = steps.split_first() else {
+        *tree = value;
+        return Ok(());
+    };
+    if *tree == Value::None {
+        *tree = match step {
+            TreeStep::Field(_) => Value::Map(HashMap::new()),
+            TreeStep::Index(_) => Value::List(vec![]),
+        };
+    }
+    let child = match (step, tree) {
+        (TreeStep::Field(name), Value::Map(entries)) => entries.entry(name.to_string()).or_insert(Value::None),
+        (TreeStep::Index(index), Value::List(values)) => {
+            let index = *index as usize;
+            if values.len() <= index {
+                values.resize(index + 1, Value::None);
+            }
+            &mut values[index]
+        },
+        (_, tree) => return Err(ParserError::SerdeError(format!("conflicting data in mapper for {:?}", tree))),
+    };
+    insert_at(child, steps, value)
+}
+
+/// Builds tree of the mapper data. Values inside RepeatMany specs are added to the list of the RepeatMany spec
+/// and key-value pairs are added to the map of their innermost RepeatMany spec
+fn mapper_to_tree(mapper: &dyn Mapper) -> Result<Value, ParserError>{
+    let spec_names = SpecNames::new(mapper);
+    let spec_data = mapper.get_spec_data();
+    let mut tree = Value::Map(HashMap::new());
+    let simple = by_shape(spec_names.simple.iter().map(|(name, template)| (template, (name, repeat_names(template)))));
+    for (path, value) in spec_data {
+        if *value == Value::None {
+            continue;
+        }
+        let Some(((name, repeats), indexes)) = find_template(&simple, path) else {
+            continue;
+        };
+        let mut steps = repeat_steps(repeats, &indexes);
+        steps.push(TreeStep::Field(name));
+        insert_at(&mut tree, &steps, value.clone())?;
+    }
+    let key_values = by_shape(spec_names.key_values.iter().map(|(_, _, key_template)| (key_template, repeat_names(key_template))));
+    for (key_path, value_path) in mapper.get_mapping_data() {
+        let Some((repeats, indexes)) = find_template(&key_values, key_path) else {
+            continue;
+        };
+        let (Some(key), Some(value)) = (spec_data.get(key_path).and_then(|key| key.get_string_value()), spec_data.get(value_path)) else {
+            continue;
+        };
+        let outer = repeats.len().saturating_sub(1);
+        let mut steps = repeat_steps(&repeats[..outer], &indexes[..outer]);
+        steps.extend(repeats.last().map(|name| TreeStep::Field(name)));
+        steps.push(TreeStep::Field(&key));
+        insert_at(&mut tree, &steps, value.clone())?;
+    }
+    Ok(tree)
+}
+
+/// Sequence of bytes is serialized as list of u8 values, it is converted back to bytes
+fn field_value(value: Value) -> Value{
+    match value {
+        Value::List(values) if values.iter().all(|value| matches!(value, Value::UnSignedNumber8(_))) => {
+            Value::U8Vec(values.iter().filter_map(|value| value.get_unsigned_num_8_value()).collect())
+        },
+        value => value,
+    }
+}
+
+fn add_entries<I>(info_provider: &mut I, spec_names: &SpecNames, entries: HashMap<String, Value>, indexes: &[u32]) -> Result<(), ParserError>
+where I: InfoProvider + ?Sized,
+{
+    for (name, value) in entries {
+        match (value, spec_names.key_value_of(&name)) {
+            (Value::None, _) => {},
+            (Value::List(items), _) if spec_names.is_repeat(&name) => {
+                for (index, item) in items.into_iter().enumerate() {
+                    let Value::Map(item_entries) = item else {
+                        return Err(ParserError::SerdeError(format!("entry {} of {} is not a struct or map", index, name)));
+                    };
+                    let mut item_indexes = indexes.to_vec();
+                    item_indexes.push(index as u32);
+                    add_entries(info_provider, spec_names, item_entries, &item_indexes)?;
+                }
+            },
+            (Value::Map(pairs), Some((key_name, value_name))) => {
+                for (key, value) in pairs {
+                    info_provider.get_mapper_mut().add_to_key_value_list_at(key, field_value(value), key_name.clone(), value_name.clone(), indexes)?;
+                }
+            },
+            (value, _) if indexes.is_empty() => info_provider.add_info(name, field_value(value))?,
+            (value, _) => info_provider.get_mapper_mut().add_simple_data_at(name, field_value(value), indexes)?,
+        }
+    }
+    Ok(())
+}
+
+/// Deserializer for the top level value of InfoProvider
+struct InfoProviderDeserializer<'a, I: ?Sized>{
+    info_provider: &'a I,
+    tree: Value,
+}
+
+impl<'de, I> Deserializer<'de> for InfoProviderDeserializer<'_, I> where I: InfoProvider + ?Sized {
+    type Error = ParserError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParserError> {
+        self.tree.deserialize_any(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(self, name: &'static str, fields: &'static [&'static str], visitor: V) -> Result<V::Value, ParserError> {
+        let mut tree = self.tree;
+        if let Value::Map(entries) = &mut tree {
+            for field in fields {
+                if entries.contains_key(*field) {
+                    continue;
+                }
+                if let Some(value) = self.info_provider.get_info(field) {
+                    entries.insert(field.to_string(), value.clone());
+                }
+            }
+        }
+        tree.deserialize_struct(name, fields, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit unit_struct
+        newtype_struct seq tuple tuple_struct map enum identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, ParserError> for Value {
+    type Deserializer = Value;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+/// Text values are parsed when number or bool is expected e.g Content-Length header
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident: $type:ty),*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParserError> {
+                match self {
+                    Value::String(data) => {
+                        let parsed = data.parse::<$type>().map_err(|error| <ParserError as de::Error>::custom(format!("unable to parse {}: {}", data, error)))?;
+                        visitor.$visit(parsed)
+                    },
+                    value => value.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Value {
+    type Error = ParserError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParserError> {
+        match self {
+            Value::String(data) => visitor.visit_string(data),
+            Value::SignedNumber64(num) => visitor.visit_i64(num),
+            Value::UnSignedNumber64(num) => visitor.visit_u64(num),
+            Value::UnSignedNumber32(num) => visitor.visit_u32(num),
+            Value::SignedNumber32(num) => visitor.visit_i32(num),
+            Value::SignedNumber16(num) => visitor.visit_i16(num),
+            Value::UnSignedNumber16(num) => visitor.visit_u16(num),
+            Value::UnSignedNumber8(num) => visitor.visit_u8(num),
+            Value::SignedNumber8(num) => visitor.visit_i8(num),
+            Value::Float32(num) => visitor.visit_f32(num),
+            Value::Float64(num) => visitor.visit_f64(num),
+            Value::Bool(flag) => visitor.visit_bool(flag),
+            Value::U8Vec(data) => visitor.visit_byte_buf(data),
+            value @ (Value::Timestamp(_) | Value::IpAddr(_) | Value::SocketAddr(_) | Value::MacAddr(_)) => {
+                visitor.visit_string(value.get_string_value_unchecked()?)
+            },
+            Value::List(values) => {
+                let mut deserializer = SeqDeserializer::<_, ParserError>::new(values.into_iter());
+                let value = visitor.visit_seq(&mut deserializer)?;
+                deserializer.end()?;
+                Ok(value)
+            },
+            Value::Map(entries) => {
+                let mut deserializer = MapDeserializer::<_, ParserError>::new(entries.into_iter());
+                let value = visitor.visit_map(&mut deserializer)?;
+                deserializer.end()?;
+                Ok(value)
+            },
+            Value::None => visitor.visit_unit(),
+        }
+    }
+
+    deserialize_parsed!(
+        deserialize_bool => visit_bool: bool,
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64
+    );
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParserError> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParserError> {
+        match self {
+            Value::U8Vec(data) => visitor.visit_string(String::from_utf8(data).map_err(<ParserError as de::Error>::custom)?),
+            Value::List(_) | Value::Map(_) | Value::None => self.deserialize_any(visitor),
+            value => visitor.visit_string(value.get_string_value_unchecked()?),
+        }
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParserError> {
+        self.deserialize_byte_buf(visitor)
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParserError> {
+        match self {
+            Value::String(data) => visitor.visit_byte_buf(data.into_bytes()),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParserError> {
+        match self {
+            Value::U8Vec(data) => {
+                let mut deserializer = SeqDeserializer::<_, ParserError>::new(data.into_iter());
+                let value = visitor.visit_seq(&mut deserializer)?;
+                deserializer.end()?;
+                Ok(value)
+            },
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ParserError> {
+        match self {
+            Value::None => visitor.visit_none(),
+            value => visitor.visit_some(value),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, ParserError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Enum is deserialized from the name of unit variant or from map of variant name to its value e.g value of OneOfSpecs
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, ParserError> {
+        match self {
+            Value::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            Value::Map(entries) if entries.len() == 1 => visitor.visit_enum(MapAccessDeserializer::new(MapDeserializer::new(entries.into_iter()))),
+            value => Err(de::Error::custom(format!("expected enum variant, found {:?}", value))),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char unit unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Serializer that serializes data into Value
+struct ValueSerializer;
+
+impl Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = ParserError;
+    type SerializeSeq = ListSerializer;
+    type SerializeTuple = ListSerializer;
+    type SerializeTupleStruct = ListSerializer;
+    type SerializeTupleVariant = ListSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = MapSerializer;
+
+    fn serialize_bool(self, flag: bool) -> Result<Value, ParserError> {
+        Ok(Value::Bool(flag))
+    }
+
+    fn serialize_i8(self, num: i8) -> Result<Value, ParserError> {
+        Ok(Value::SignedNumber8(num))
+    }
+
+    fn serialize_i16(self, num: i16) -> Result<Value, ParserError> {
+        Ok(Value::SignedNumber16(num))
+    }
+
+    fn serialize_i32(self, num: i32) -> Result<Value, ParserError> {
+        Ok(Value::SignedNumber32(num))
+    }
+
+    fn serialize_i64(self, num: i64) -> Result<Value, ParserError> {
+        Ok(Value::SignedNumber64(num))
+    }
+
+    fn serialize_u8(self, num: u8) -> Result<Value, ParserError> {
+        Ok(Value::UnSignedNumber8(num))
+    }
+
+    fn serialize_u16(self, num: u16) -> Result<Value, ParserError> {
+        Ok(Value::UnSignedNumber16(num))
+    }
+
+    fn serialize_u32(self, num: u32) -> Result<Value, ParserError> {
+        Ok(Value::UnSignedNumber32(num))
+    }
+
+    fn serialize_u64(self, num: u64) -> Result<Value, ParserError> {
+        Ok(Value::UnSignedNumber64(num))
+    }
+
+    fn serialize_f32(self, num: f32) -> Result<Value, ParserError> {
+        Ok(Value::Float32(num))
+    }
+
+    fn serialize_f64(self, num: f64) -> Result<Value, ParserError> {
+        Ok(Value::Float64(num))
+    }
+
+    fn serialize_char(self, data: char) -> Result<Value, ParserError> {
+        Ok(Value::String(data.to_string()))
+    }
+
+    fn serialize_str(self, data: &str) -> Result<Value, ParserError> {
+        Ok(Value::String(data.to_owned()))
+    }
+
+    fn serialize_bytes(self, data: &[u8]) -> Result<Value, ParserError> {
+        Ok(Value::U8Vec(data.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Value, ParserError> {
+        Ok(Value::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, ParserError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, ParserError> {
+        Ok(Value::None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, ParserError> {
+        Ok(Value::None)
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<Value, ParserError> {
+        Ok(Value::String(variant.to_owned()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<Value, ParserError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(self, _name: &'static str, _variant_index: u32, variant: &'static str, value: &T) -> Result<Value, ParserError> {
+        Ok(Value::Map(HashMap::from([(variant.to_owned(), value.serialize(self)?)])))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<ListSerializer, ParserError> {
+        Ok(ListSerializer{ variant: None, values: Vec::with_capacity(len.unwrap_or_default()) })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<ListSerializer, ParserError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<ListSerializer, ParserError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str, len: usize) -> Result<ListSerializer, ParserError> {
+        Ok(ListSerializer{ variant: Some(variant), values: Vec::with_capacity(len) })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSerializer, ParserError> {
+        Ok(MapSerializer{ variant: None, entries: HashMap::new(), key: None })
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<MapSerializer, ParserError> {
+        self.serialize_map(None)
+    }
+
+    fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str, _len: usize) -> Result<MapSerializer, ParserError> {
+        Ok(MapSerializer{ variant: Some(variant), entries: HashMap::new(), key: None })
+    }
+}
+
+/// Wraps value of enum variant in a map of variant name to the value
+fn wrap_variant(variant: Option<&'static str>, value: Value) -> Value{
+    match variant {
+        Some(variant) => Value::Map(HashMap::from([(variant.to_owned(), value)])),
+        None => value,
+    }
+}
+
+/// Serializes sequences and tuples into `Value::List`
+struct ListSerializer{
+    variant: Option<&'static str>,
+    values: Vec<Value>,
+}
+
+impl ser::SerializeSeq for ListSerializer {
+    type Ok = Value;
+    type Error = ParserError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ParserError> {
+        self.values.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, ParserError> {
+        Ok(wrap_variant(self.variant, Value::List(self.values)))
+    }
+}
+
+impl ser::SerializeTuple for ListSerializer {
+    type Ok = Value;
+    type Error = ParserError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ParserError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, ParserError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for ListSerializer {
+    type Ok = Value;
+    type Error = ParserError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ParserError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, ParserError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for ListSerializer {
+    type Ok = Value;
+    type Error = ParserError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ParserError> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, ParserError> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+/// Serializes maps and structs into `Value::Map`. Keys of map are serialized as string
+struct MapSerializer{
+    variant: Option<&'static str>,
+    entries: HashMap<String, Value>,
+    key: Option<String>,
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Value;
+    type Error = ParserError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), ParserError> {
+        let key = key.serialize(ValueSerializer)?;
+        self.key = Some(key.get_string_value().ok_or_else(|| ParserError::SerdeError(format!("map key {:?} is not a string", key)))?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ParserError> {
+        let key = self.key.take().ok_or_else(|| ParserError::SerdeError("map value is serialized before key".to_owned()))?;
+        self.entries.insert(key, value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, ParserError> {
+        Ok(wrap_variant(self.variant, Value::Map(self.entries)))
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = Value;
+    type Error = ParserError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), ParserError> {
+        self.entries.insert(key.to_owned(), value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, ParserError> {
+        ser::SerializeMap::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for MapSerializer {
+    type Ok = Value;
+    type Error = ParserError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), ParserError> {
+        ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<Value, ParserError> {
+        ser::SerializeMap::end(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Serialize};
+    use tokio::io::BufReader;
+
+    use crate::core::builders::{new_spec_builder, DelimitedStringSpecBuilder, DelimiterBuilder, InlineValueBuilder, KeySpecBuilder, NumberSpecBuilder, ProtoSpecBuilder, RepeatBuilder, ValueBuilder};
+    use crate::core::{DefaultSerializer, LengthEncoding, ListSpec, RequestSerializer, SpecName, ValueType};
+    use crate::mapping_extractor::{DefaultMapper, SpecTraverse};
+    use crate::test_utils::TestRequestInfo;
+
+    use super::{from_info_provider, from_value, to_info_provider, to_value};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Message{
+        part_count: u8,
+        parts: Vec<Part>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Part{
+        part_name: String,
+        header_count: u8,
+        headers: HashMap<String, String>,
+        #[serde(default)]
+        missing: Option<String>,
+    }
+
+    fn build_message_spec() -> ListSpec {
+        let header = new_spec_builder(SpecName::Name("header".to_string()))
+            .key_follows(SpecName::Name("header_name".to_owned()), false)
+            .expect_string(SpecName::NoName, false)
+            .delimited_by(": ".to_string())
+            .value_follows(SpecName::Name("header_value".to_owned()), false)
+            .expect_string(SpecName::NoName, false)
+            .delimited_by_newline()
+            .build();
+        let part = new_spec_builder(SpecName::Name("part".to_string()))
+            .inline_value_follows(SpecName::Name("part_name".to_string()), false)
+            .expect_string(SpecName::NoName, false)
+            .delimited_by_newline()
+            .inline_value_follows(SpecName::Name("header_count".to_string()), false)
+            .expect_u8(SpecName::NoName, false)
+            .repeat_with_count_from(SpecName::Name("headers".to_string()), false, "header_count".to_string(), LengthEncoding::Binary(ValueType::UnSignedNumber8), header)
+            .build();
+        new_spec_builder(SpecName::NoName)
+            .inline_value_follows(SpecName::Name("part_count".to_string()), false)
+            .expect_u8(SpecName::NoName, false)
+            .repeat_with_count_from(SpecName::Name("parts".to_string()), false, "part_count".to_string(), LengthEncoding::Binary(ValueType::UnSignedNumber8), part)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_serde_bridge() {
+        let data: &[u8] = b"\x02first\r\n\x01a: 1\r\nsecond\r\n\x01b: 2\r\n";
+        let expected = Message{
+            part_count: 2,
+            parts: vec![
+                Part{ part_name: "first".to_string(), header_count: 1, headers: HashMap::from([("a".to_string(), "1".to_string())]), missing: None },
+                Part{ part_name: "second".to_string(), header_count: 1, headers: HashMap::from([("b".to_string(), "2".to_string())]), missing: None },
+            ],
+        };
+        let spec = build_message_spec();
+        let mut mapper = DefaultMapper::new();
+        assert!(spec.traverse(&mut mapper ).is_ok());
+
+        let mut request_info = TestRequestInfo::new();
+        request_info.0 = mapper.clone();
+        let result = DefaultSerializer{}.deserialize_from(&mut request_info, BufReader::new(data), &spec).await;
+        assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+        let message = from_info_provider::<Message, _>(&request_info);
+        assert_eq!(message.ok(), Some(expected));
+
+        // struct is added to info provider and serialized using the spec
+        let message = from_info_provider::<Message, _>(&request_info).unwrap();
+        let mut request_info = TestRequestInfo::new();
+        request_info.0 = mapper;
+        let result = to_info_provider(&message, &mut request_info);
+        assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+        let mut buffer = Vec::new();
+        let result = DefaultSerializer{}.serialize_to(&mut request_info, &mut buffer, Box::new(spec)).await;
+        assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+        assert_eq!(buffer, data);
+
+        let value = to_value(&message);
+        assert!(value.is_ok(), "expected success, but got error {:?}", value.err());
+        assert_eq!(from_value::<Message>(value.unwrap()).ok(), Some(message));
+    }
+}
@@ -0,0 +1,1449 @@
+use chrono::DateTime;
+use tokio::io::BufReader;
+
+use crate::core::builders::{new_spec_builder, CompositeBuilder, DelimitedStringSpecBuilder, ComputedSpecBuilder, DelimiterBuilder, EnumMapBuilder, InlineValueBuilder, KeySpecBuilder, BytesSpecBuilder, LengthFromFieldSpecBuilder, NumberSpecBuilder, ProtoSpecBuilder, RepeatBuilder, SwitchBuilder, TextNumberSpecBuilder, TimestampSpecBuilder, AddressSpecBuilder, ValueBuilder, StringSpecBuilder};
+use crate::core::{AddressKind, Alignment, Separator, SpecDeserialize, BitField, EpochUnit, TimestampFormat, CharClass, ChecksumAlgorithm, CStringSpec, Endianness, UnmappedValue, InfoProvider, LengthEncoding, ListSpec, Mapper, ParserError, SpecName, Value, ValueExtractor, ValueType, ValueValidator};
+use crate::core::protocol_reader::ProtocolBuffReader;
+use crate::test_utils::{deserialize_with, new_request_info, serialize_with, TestRequestInfo};
+
+#[tokio::test]
+async fn test_sixteen_bit_numbers_read_two_bytes() {
+    let data: &[u8] = &[0x01, 0x02, 0xFF, 0xFE, 0x07];
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("u16".to_string()), false)
+        .expect_u16(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("i16".to_string()), false)
+        .expect_i16(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("u8".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("u16"), Some(&Value::UnSignedNumber16(0x0102)));
+    assert_eq!(request_info.get_info("i16"), Some(&Value::SignedNumber16(-2)));
+    assert_eq!(request_info.get_info("u8"), Some(&Value::UnSignedNumber8(7)));
+}
+
+#[tokio::test]
+async fn test_mixed_endianness_numbers() {
+    let data: &[u8] = &[0x01, 0x02, 0x04, 0x03, 0x02, 0x01, 0xFE, 0xFF, 0, 0, 0, 0, 0, 0, 0, 0x2A];
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("be_u16".to_string()), false)
+        .expect_u16(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("le_u32".to_string()), false)
+        .expect_u32_le(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("le_i16".to_string()), false)
+        .expect_i16_le(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("be_u64".to_string()), false)
+        .expect_u64(SpecName::NoName, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert!(matches!(request_info.get_info("be_u16"), Some(Value::UnSignedNumber16(0x0102))));
+    assert!(matches!(request_info.get_info("le_u32"), Some(Value::UnSignedNumber32(0x01020304))));
+    assert!(matches!(request_info.get_info("le_i16"), Some(Value::SignedNumber16(-2))));
+    assert!(matches!(request_info.get_info("be_u64"), Some(Value::UnSignedNumber64(42))));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+#[tokio::test]
+async fn test_little_endian_numbers() {
+    let mut data = vec![0x02, 0x01];
+    data.extend_from_slice(&42u64.to_le_bytes());
+    data.extend_from_slice(&(-42i64).to_le_bytes());
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("u16".to_string()), false)
+        .expect_u16_le(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("u64".to_string()), false)
+        .expect_u64_le(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("i64".to_string()), false)
+        .expect_i64_le(SpecName::NoName, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, &data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("u16"), Some(&Value::UnSignedNumber16(0x0102)));
+    assert_eq!(request_info.get_info("u64"), Some(&Value::UnSignedNumber64(42)));
+    assert_eq!(request_info.get_info("i64"), Some(&Value::SignedNumber64(-42)));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+#[tokio::test]
+async fn test_primitive_numbers_and_bool() {
+    let mut data = vec![0xFF, 0x80];
+    data.extend_from_slice(&(-70000i32).to_le_bytes());
+    data.extend_from_slice(&1.5f32.to_be_bytes());
+    data.extend_from_slice(&(-2.25f64).to_le_bytes());
+    data.push(1);
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("u8".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("i8".to_string()), false)
+        .expect_i8(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("i32".to_string()), false)
+        .expect_i32_le(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("f32".to_string()), false)
+        .expect_f32(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("f64".to_string()), false)
+        .expect_f64_le(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("flag".to_string()), false)
+        .expect_bool(SpecName::NoName, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, &data[..], &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("u8"), Some(&Value::UnSignedNumber8(255)));
+    assert_eq!(request_info.get_info("i8"), Some(&Value::SignedNumber8(-128)));
+    assert_eq!(request_info.get_info("i32"), Some(&Value::SignedNumber32(-70000)));
+    assert_eq!(request_info.get_info("f32"), Some(&Value::Float32(1.5)));
+    assert_eq!(request_info.get_info("f64"), Some(&Value::Float64(-2.25)));
+    assert_eq!(request_info.get_info("flag"), Some(&Value::Bool(true)));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+#[tokio::test]
+async fn test_varint_specs() {
+    let data: &[u8] = &[0xAC, 0x02, 0x05, 0x01];
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("length".to_string()), false)
+        .expect_varint(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("delta".to_string()), false)
+        .expect_zigzag_varint(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("small".to_string()), false)
+        .expect_varint(SpecName::NoName, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("length"), Some(&Value::UnSignedNumber64(300)));
+    assert_eq!(request_info.get_info("delta"), Some(&Value::SignedNumber64(-3)));
+    assert_eq!(request_info.get_info("small"), Some(&Value::UnSignedNumber64(1)));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+#[tokio::test]
+async fn test_varint_overflow_error() {
+    let data: &[u8] = &[0xFF; 11];
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("length".to_string()), false)
+        .expect_varint(SpecName::NoName, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn test_number_written_with_spec_width() {
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("port".to_string()), false)
+        .expect_u16(SpecName::NoName, false)
+        .build();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("port".to_string(), Value::UnSignedNumber32(5)).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), vec![0, 5]);
+}
+
+#[tokio::test]
+async fn test_number_overflowing_spec_width() {
+    let build_spec = || new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("count".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .build();
+    let spec = build_spec();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("count".to_string(), Value::UnSignedNumber16(256)).is_ok());
+    let result = serialize_with(&mut request_info, build_spec()).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("count".to_string(), Value::SignedNumber32(-1)).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn test_number_of_mismatched_type() {
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("ratio".to_string()), false)
+        .expect_f32(SpecName::NoName, false)
+        .build();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("ratio".to_string(), Value::UnSignedNumber32(5)).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn test_length_from_field() {
+    let data: &[u8] = b"\x00\x05hello5\r\nworld";
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("payload_length".to_string()), false)
+        .expect_u16(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("payload".to_string()), false)
+        .expect_string_with_length_from(SpecName::NoName, "payload_length".to_string(), LengthEncoding::Binary(ValueType::UnSignedNumber16), false)
+        .inline_value_follows(SpecName::Name("trailer_length".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .inline_value_follows(SpecName::Name("trailer".to_string()), false)
+        .expect_bytes_with_length_from(SpecName::NoName, "trailer_length".to_string(), LengthEncoding::Decimal, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("payload"), Some(&Value::String("hello".to_string())));
+    assert_eq!(request_info.get_info("trailer"), Some(&Value::U8Vec(b"world".to_vec())));
+
+    // length fields are computed from the data during serialization
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("payload".to_string(), Value::String("hello".to_string())).is_ok());
+    assert!(request_info.add_info("trailer".to_string(), Value::U8Vec(b"world".to_vec())).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+#[tokio::test]
+async fn test_length_from_field_invalid_length() {
+    let data: &[u8] = b"five\r\nhello";
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("length".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .inline_value_follows(SpecName::Name("payload".to_string()), false)
+        .expect_bytes_with_length_from(SpecName::NoName, "length".to_string(), LengthEncoding::Decimal, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn test_repeat_count_from_field() {
+    let data: &[u8] = b"\x02a: 1\r\nb: 2\r\n";
+    let entry = new_spec_builder(SpecName::Name("entry".to_string()))
+        .key_follows(SpecName::Name("entry_name".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by(": ".to_string())
+        .value_follows(SpecName::Name("entry_value".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .build();
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("entry_count".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .repeat_with_count_from(SpecName::Name("entries".to_string()), false, "entry_count".to_string(), LengthEncoding::Binary(ValueType::UnSignedNumber8), entry)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_key_value_info_by_spec_name("a".to_owned(), &"entry_name".to_owned()), Some(&Value::String("1".to_string())));
+    assert_eq!(request_info.get_key_value_info_by_spec_name("b".to_owned(), &"entry_name".to_owned()), Some(&Value::String("2".to_string())));
+
+    // stale count is replaced by the number of entries during serialization
+    assert!(request_info.add_info("entry_count".to_string(), Value::UnSignedNumber8(5)).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+#[tokio::test]
+async fn test_repeat_entries_start_at_index_zero() {
+    let data: &[u8] = b"\x02\x0a\x0b";
+    let entry = new_spec_builder(SpecName::Name("entry".to_string()))
+        .inline_value_follows(SpecName::Name("item".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .build();
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("item_count".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .repeat_with_count_from(SpecName::Name("items".to_string()), false, "item_count".to_string(), LengthEncoding::Binary(ValueType::UnSignedNumber8), entry)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    // the repeat index is advanced after an entry is parsed, so the first entry is stored at index 0
+    // like the serializer expects it
+    let mapper = request_info.get_mapper();
+    assert_eq!(mapper.get_value_by_key_at("item", &[0]), Some(&Value::UnSignedNumber8(0x0a)));
+    assert_eq!(mapper.get_value_by_key_at("item", &[1]), Some(&Value::UnSignedNumber8(0x0b)));
+    assert_eq!(mapper.get_value_by_key_at("item", &[2]), None);
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+#[tokio::test]
+async fn test_nested_repeat() {
+    let data: &[u8] = b"\x02first\r\n\x01a: 1\r\nsecond\r\n\x02b: 2\r\nc: 3\r\n";
+    let header = new_spec_builder(SpecName::Name("header".to_string()))
+        .key_follows(SpecName::Name("header_name".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by(": ".to_string())
+        .value_follows(SpecName::Name("header_value".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .build();
+    let part = new_spec_builder(SpecName::Name("part".to_string()))
+        .inline_value_follows(SpecName::Name("part_name".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .inline_value_follows(SpecName::Name("header_count".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .repeat_with_count_from(SpecName::Name("headers".to_string()), false, "header_count".to_string(), LengthEncoding::Binary(ValueType::UnSignedNumber8), header)
+        .build();
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("part_count".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .repeat_with_count_from(SpecName::Name("parts".to_string()), false, "part_count".to_string(), LengthEncoding::Binary(ValueType::UnSignedNumber8), part)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    let mapper = request_info.get_mapper();
+    assert_eq!(mapper.get_value_by_key_at("part_name", &[0]), Some(&Value::String("first".to_string())));
+    assert_eq!(mapper.get_value_by_key_at("part_name", &[1]), Some(&Value::String("second".to_string())));
+    assert_eq!(mapper.get_value_by_key_at("header_count", &[1]), Some(&Value::UnSignedNumber8(2)));
+    assert_eq!(mapper.get_value_from_key_value_list_at("a".to_owned(), "header_name", &[0]), Some(&Value::String("1".to_string())));
+    assert_eq!(mapper.get_value_from_key_value_list_at("a".to_owned(), "header_name", &[1]), None);
+    assert_eq!(mapper.get_value_from_key_value_list_at("c".to_owned(), "header_name", &[1]), Some(&Value::String("3".to_string())));
+
+    // nested entries are added with the index of the enclosing repeat spec and counts are computed
+    let mut request_info = new_request_info(&spec);
+    let mapper = &mut request_info.0;
+    assert!(mapper.add_simple_data_at("part_name".to_string(), Value::String("first".to_string()), &[0]).is_ok());
+    assert!(mapper.add_to_key_value_list_at("a".to_string(), Value::String("1".to_string()), "header_name".to_string(), "header_value".to_string(), &[0]).is_ok());
+    assert!(mapper.add_simple_data_at("part_name".to_string(), Value::String("second".to_string()), &[1]).is_ok());
+    assert!(mapper.add_to_key_value_list_at("b".to_string(), Value::String("2".to_string()), "header_name".to_string(), "header_value".to_string(), &[1]).is_ok());
+    assert!(mapper.add_to_key_value_list_at("c".to_string(), Value::String("3".to_string()), "header_name".to_string(), "header_value".to_string(), &[1]).is_ok());
+    assert!(mapper.add_to_key_value_list_at("d".to_string(), Value::String("4".to_string()), "header_name".to_string(), "header_value".to_string(), &[]).is_err());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+fn build_switch_spec() -> ListSpec {
+    let login = new_spec_builder(SpecName::Name("login".to_string()))
+        .inline_value_follows(SpecName::Name("user".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .build();
+    let data = new_spec_builder(SpecName::Name("data".to_string()))
+        .inline_value_follows(SpecName::Name("payload".to_string()), false)
+        .expect_u16(SpecName::NoName, false)
+        .build();
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("opcode".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .expect_switch(SpecName::Name("message".to_string()), false, "opcode".to_string(),
+            vec![(Value::UnSignedNumber8(1), login), (Value::UnSignedNumber8(2), data)], None)
+        .build()
+}
+
+#[tokio::test]
+async fn test_switch_spec() {
+    for input in [b"\x01alice\r\n".as_ref(), b"\x02\x01\x02".as_ref()] {
+        let spec = build_switch_spec();
+        let mut request_info = new_request_info(&spec);
+        let result = deserialize_with(&mut request_info, input, &spec).await;
+        assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+        if input[0] == 1 {
+            assert_eq!(request_info.get_info("user"), Some(&Value::String("alice".to_string())));
+            assert_eq!(request_info.get_info("payload"), None);
+        } else {
+            assert_eq!(request_info.get_info("payload"), Some(&Value::UnSignedNumber16(0x0102)));
+            assert_eq!(request_info.get_info("user"), None);
+        }
+
+        // branch is selected from the stored opcode
+        let result = serialize_with(&mut request_info, spec).await;
+        assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+        assert_eq!(result.unwrap(), input);
+    }
+
+    let spec = build_switch_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"\x03".as_ref(), &spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+}
+
+fn build_one_of_specs() -> ListSpec {
+    let status = new_spec_builder(SpecName::Name("status".to_string()))
+        .inline_value_follows(SpecName::Name("code".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_space()
+        .inline_value_follows(SpecName::NoName, false)
+        .expect_exact_string(SpecName::NoName, "OK".to_string(), false)
+        .expect_newline()
+        .build();
+    let text = new_spec_builder(SpecName::Name("text".to_string()))
+        .inline_value_follows(SpecName::Name("line".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .build();
+    new_spec_builder(SpecName::NoName)
+        .expect_one_of_specs(SpecName::Name("reply".to_string()), false, vec![status, text])
+        .build()
+}
+
+#[tokio::test]
+async fn test_one_of_specs() {
+    let data: &[u8] = b"250 done\r\n";
+    let spec = build_one_of_specs();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("reply"), Some(&Value::String("text".to_string())));
+    assert_eq!(request_info.get_info("line"), Some(&Value::String("250 done".to_string())));
+    // data parsed by the failed alternative is discarded
+    assert_eq!(request_info.get_info("code"), None);
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+
+    let spec = build_one_of_specs();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"250".as_ref(), &spec).await;
+    match result {
+        Err(ParserError::NoMatchingAlternative { spec_name, errors }) => {
+            assert_eq!(spec_name, "reply");
+            assert_eq!(errors.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(), vec!["status", "text"]);
+        },
+        other => panic!("expected no matching alternative error, but got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_failed_alternative_leaves_no_data() {
+    let header = new_spec_builder(SpecName::Name("header".to_string()))
+        .key_follows(SpecName::Name("header_name".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by(": ".to_string())
+        .value_follows(SpecName::Name("header_value".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_any_of(vec!["\r\n".to_string(), "\n".to_string()])
+        .inline_value_follows(SpecName::NoName, false)
+        .expect_exact_string(SpecName::NoName, "END".to_string(), false)
+        .build();
+    let text = new_spec_builder(SpecName::Name("text".to_string()))
+        .inline_value_follows(SpecName::Name("line".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .build();
+    let spec = new_spec_builder(SpecName::NoName)
+        .expect_one_of_specs(SpecName::Name("reply".to_string()), false, vec![header, text])
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let templates = request_info.get_mapper().get_mapping_data_template().clone();
+    let result = deserialize_with(&mut request_info, b"a: b\r\n", &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("line"), Some(&Value::String("a: b".to_string())));
+    let mapper = request_info.get_mapper();
+    assert_eq!(mapper.get_value_from_key_value_list("a".to_owned(), "header_name"), None);
+    assert!(mapper.get_mapping_data().is_empty());
+    assert!(mapper.get_matched_delimiters().is_some_and(|matched_delimiters| matched_delimiters.is_empty()));
+    assert_eq!(mapper.get_mapping_data_template(), &templates);
+    assert_eq!(mapper.get_spec_data().len(), 2);
+}
+
+#[tokio::test]
+async fn test_cstring_and_fixed_width_string() {
+    let data: &[u8] = b"alice\x0000042bob   main\x00";
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("user".to_string()), false)
+        .expect_cstring(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("code".to_string()), false)
+        .expect_fixed_width_string(SpecName::NoName, 5, b'0', Alignment::Right, false)
+        .inline_value_follows(SpecName::Name("name".to_string()), false)
+        .expect_fixed_width_string(SpecName::NoName, 6, b' ', Alignment::Left, false)
+        .inline_value_follows(SpecName::Name("database".to_string()), false)
+        .expect_cstring(SpecName::NoName, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("user"), Some(&Value::String("alice".to_string())));
+    assert_eq!(request_info.get_info("code"), Some(&Value::String("42".to_string())));
+    assert_eq!(request_info.get_info("name"), Some(&Value::String("bob".to_string())));
+    assert_eq!(request_info.get_info("database"), Some(&Value::String("main".to_string())));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+#[tokio::test]
+async fn test_cstring_serialize_values() {
+    let build_spec = || new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("user".to_string()), false)
+        .expect_cstring(SpecName::NoName, false)
+        .build();
+
+    let mut request_info = new_request_info(&build_spec());
+    assert!(request_info.add_info("user".to_string(), Value::U8Vec(b"alice".to_vec())).is_ok());
+    let result = serialize_with(&mut request_info, build_spec()).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), b"alice\0");
+
+    let invalid_values = [
+        Value::String("ali\0ce".to_string()),
+        Value::U8Vec(b"ali\0ce".to_vec()),
+        Value::UnSignedNumber32(5),
+    ];
+    for value in invalid_values {
+        let mut request_info = new_request_info(&build_spec());
+        assert!(request_info.add_info("user".to_string(), value).is_ok());
+        let result = serialize_with(&mut request_info, build_spec()).await;
+        assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+    }
+}
+
+#[tokio::test]
+async fn test_fixed_width_string_of_pad_bytes() {
+    let data: &[u8] = b"00000    ";
+    let build_spec = || new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("code".to_string()), false)
+        .expect_fixed_width_string(SpecName::NoName, 5, b'0', Alignment::Right, false)
+        .inline_value_follows(SpecName::Name("name".to_string()), false)
+        .expect_fixed_width_string(SpecName::NoName, 4, b' ', Alignment::Left, false)
+        .build();
+    let spec = build_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("code"), Some(&Value::String("".to_string())));
+    assert_eq!(request_info.get_info("name"), Some(&Value::String("".to_string())));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+
+    let mut request_info = new_request_info(&build_spec());
+    assert!(request_info.add_info("code".to_string(), Value::String("".to_string())).is_ok());
+    assert!(request_info.add_info("name".to_string(), Value::String("".to_string())).is_ok());
+    let result = serialize_with(&mut request_info, build_spec()).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+#[tokio::test]
+async fn test_fixed_width_string_too_long() {
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("name".to_string()), false)
+        .expect_fixed_width_string(SpecName::NoName, 4, b' ', Alignment::Left, false)
+        .build();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("name".to_string(), Value::String("alice".to_string())).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+}
+
+fn build_validated_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("method".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .validated_by(CharClass::HttpToken)
+        .delimited_by_space()
+        .inline_value_follows(SpecName::Name("id".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .validated_by(|value: &str| if value.len() == 4 { CharClass::HexDigits.validate(value) } else { Err("expected 4 hex digits".to_string()) })
+        .delimited_by_newline()
+        .build()
+}
+
+#[tokio::test]
+async fn test_validated_string() {
+    let spec = build_validated_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"GET 0a1f\r\n".as_ref(), &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"GET 0x1f\r\n".as_ref(), &spec).await;
+    match result {
+        Err(ParserError::InvalidValue { spec_path, value, .. }) => {
+            assert!(spec_path.contains("id"), "unexpected spec path {}", spec_path);
+            assert_eq!(value, "0x1f");
+        },
+        other => panic!("expected invalid value error, but got {:?}", other.err()),
+    }
+
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("method".to_string(), Value::String("GE T".to_string())).is_ok());
+    assert!(request_info.add_info("id".to_string(), Value::String("0a1f".to_string())).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { ref value, .. }) if value == "GE T"), "expected invalid value error, but got {:?}", result);
+}
+
+fn build_case_insensitive_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::NoName, false)
+        .expect_exact_string_ignore_case(SpecName::Name("verb".to_string()), "HELO".to_string(), false)
+        .expect_space()
+        .inline_value_follows(SpecName::Name("mode".to_string()), false)
+        .expect_one_of_string_ignore_case(SpecName::NoName, false, vec!["TEXT".to_string(), "BINARY".to_string()])
+        .delimited_by_newline()
+        .build()
+}
+
+#[tokio::test]
+async fn test_case_insensitive_match() {
+    let spec = build_case_insensitive_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"helo Binary\r\n".as_ref(), &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("verb"), Some(&Value::String("HELO".to_string())));
+    assert_eq!(request_info.get_info("mode"), Some(&Value::String("BINARY".to_string())));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), b"HELO BINARY\r\n");
+
+    let spec = build_case_insensitive_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"ehlo text\r\n".as_ref(), &spec).await;
+    assert!(result.is_err(), "expected error, but got success");
+}
+
+#[tokio::test]
+async fn test_exact_string_without_value() {
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("version".to_string()), true)
+        .expect_exact_string(SpecName::NoName, "V2".to_string(), true)
+        .expect_exact_string(SpecName::Delimiter, ";".to_string(), false)
+        .build();
+    // optional exact string without value is skipped while delimiter is always written
+    let mut request_info = new_request_info(&spec);
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), b";");
+
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("version".to_string()), false)
+        .expect_exact_string(SpecName::NoName, "V2".to_string(), false)
+        .build();
+    let mut request_info = new_request_info(&spec);
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(matches!(result, Err(ParserError::MissingData(_))), "expected missing data error, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn test_none_value_writes_nothing() {
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("reason".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .build();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("reason".to_string(), Value::None).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), b"\r\n");
+}
+
+#[tokio::test]
+async fn test_bytes_builders() {
+    let data: &[u8] = b"\x01\x02key: \x00\xff\x10payload";
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("tag".to_string()), false)
+        .expect_bytes(SpecName::NoName, 2, false)
+        .key_follows(SpecName::Name("field_name".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by(": ".to_string())
+        .value_follows(SpecName::Name("field_value".to_owned()), false)
+        .expect_bytes(SpecName::NoName, 3, false)
+        .inline_value_follows(SpecName::Name("payload".to_string()), false)
+        .expect_remaining_bytes(SpecName::NoName, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("tag"), Some(&Value::U8Vec(vec![1, 2])));
+    assert_eq!(request_info.get_info("payload"), Some(&Value::U8Vec(b"payload".to_vec())));
+    assert_eq!(request_info.get_mapper().get_value_from_key_value_list("key".to_owned(), "field_name"), Some(&Value::U8Vec(vec![0, 0xff, 0x10])));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+fn build_byte_keys_spec() -> ListSpec {
+    let chunk = new_spec_builder(SpecName::Name("chunk".to_string()))
+        .key_follows(SpecName::Name("chunk_type".to_owned()), false)
+        .expect_bytes(SpecName::NoName, 4, false)
+        .value_follows(SpecName::Name("chunk_value".to_owned()), false)
+        .expect_u8(SpecName::NoName, false)
+        .build();
+    let flag = new_spec_builder(SpecName::Name("flag".to_string()))
+        .inline_value_follows(SpecName::Name("flag_bit".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .build();
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("chunks".to_string()), false)
+        .repeat_n_times(SpecName::NoName, false, 2, chunk)
+        .key_follows(SpecName::Name("option_name".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by(": ".to_string())
+        .value_follows(SpecName::Name("option_flags".to_owned()), false)
+        .repeat_n_times(SpecName::NoName, false, 2, flag)
+        .build()
+}
+
+#[tokio::test]
+async fn test_byte_keys_and_repeat_as_value() {
+    let data: &[u8] = b"IHDR\x01IEND\x02flags: \x0a\x0b";
+    let spec = build_byte_keys_spec();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_key_value_info_by_spec_name("IHDR".to_owned(), &"chunk_type".to_owned()), Some(&Value::UnSignedNumber8(1)));
+    assert_eq!(request_info.get_key_value_info_by_spec_name("IEND".to_owned(), &"chunk_type".to_owned()), Some(&Value::UnSignedNumber8(2)));
+    assert_eq!(request_info.get_mapper().get_value_by_key_at("flag_bit", &[1]), Some(&Value::UnSignedNumber8(0x0b)));
+
+    let spec = build_byte_keys_spec();
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+fn build_bit_field_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("id".to_string()), false)
+        .expect_u16(SpecName::NoName, false)
+        .expect_bit_fields(SpecName::Name("flags".to_string()), false, ValueType::UnSignedNumber16, Endianness::Big, vec![
+            BitField::new("qr", 1), BitField::new("opcode", 4), BitField::new("aa", 1), BitField::new("tc", 1),
+            BitField::new("rd", 1), BitField::new("ra", 1), BitField::new("z", 3), BitField::new("rcode", 4),
+        ])
+        .expect_bit_fields(SpecName::Name("fixed_header".to_string()), false, ValueType::UnSignedNumber8, Endianness::Big, vec![
+            BitField::new("packet_type", 4), BitField::new("qos", 2),
+        ])
+        .build()
+}
+
+#[tokio::test]
+async fn test_bit_fields() {
+    let data: &[u8] = b"\x12\x34\x81\x83\x34";
+    let spec = build_bit_field_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("id"), Some(&Value::UnSignedNumber16(0x1234)));
+    assert_eq!(request_info.get_info("qr"), Some(&Value::UnSignedNumber16(1)));
+    assert_eq!(request_info.get_info("opcode"), Some(&Value::UnSignedNumber16(0)));
+    assert_eq!(request_info.get_info("rd"), Some(&Value::UnSignedNumber16(1)));
+    assert_eq!(request_info.get_info("ra"), Some(&Value::UnSignedNumber16(1)));
+    assert_eq!(request_info.get_info("rcode"), Some(&Value::UnSignedNumber16(3)));
+    assert_eq!(request_info.get_info("packet_type"), Some(&Value::UnSignedNumber8(3)));
+    assert_eq!(request_info.get_info("qos"), Some(&Value::UnSignedNumber8(1)));
+
+    // reserved bits after the last field are written as zero
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), b"\x12\x34\x81\x83\x34");
+
+    let spec = build_bit_field_spec();
+    let mut request_info = new_request_info(&spec);
+    for (field, value) in [("id", 1), ("qr", 0), ("opcode", 2), ("aa", 0), ("tc", 0), ("rd", 0), ("ra", 0), ("z", 0), ("rcode", 16), ("packet_type", 1), ("qos", 0)] {
+        assert!(request_info.add_info(field.to_string(), Value::UnSignedNumber16(value)).is_ok());
+    }
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { ref spec_path, .. }) if spec_path.ends_with("rcode")), "expected invalid value error, but got {:?}", result);
+}
+
+fn build_optional_bit_field_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("id".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .expect_bit_fields(SpecName::Name("flags".to_string()), true, ValueType::UnSignedNumber8, Endianness::Big, vec![
+            BitField::new("kind", 4), BitField::new("level", 2),
+        ])
+        .build()
+}
+
+#[tokio::test]
+async fn test_optional_bit_fields() {
+    // word is skipped when none of the fields are available
+    let spec = build_optional_bit_field_spec();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("id".to_string(), Value::UnSignedNumber8(7)).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), b"\x07");
+
+    // partially available word is not skipped
+    let spec = build_optional_bit_field_spec();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("id".to_string(), Value::UnSignedNumber8(7)).is_ok());
+    assert!(request_info.add_info("kind".to_string(), Value::UnSignedNumber8(2)).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(matches!(result, Err(ParserError::MissingData(ref field)) if field == "level"), "expected missing data error, but got {:?}", result);
+
+    // bits after the last field are zero filled
+    let spec = build_optional_bit_field_spec();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("id".to_string(), Value::UnSignedNumber8(7)).is_ok());
+    assert!(request_info.add_info("kind".to_string(), Value::UnSignedNumber8(2)).is_ok());
+    assert!(request_info.add_info("level".to_string(), Value::UnSignedNumber8(3)).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), b"\x07\x2c");
+}
+
+fn build_enum_map_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("opcode".to_string()), false)
+        .expect_u8_enum(SpecName::NoName, false, vec![(1, "OP_QUERY".to_string()), (2, "OP_REPLY".to_string())], UnmappedValue::Fail)
+        .inline_value_follows(SpecName::Name("status".to_string()), false)
+        .expect_enum_map(SpecName::NoName, false, Box::new(CStringSpec::new(SpecName::NoName, false)),
+            vec![(Value::String("404".to_string()), "NOT_FOUND".to_string())], UnmappedValue::PassThrough)
+        .build()
+}
+
+#[tokio::test]
+async fn test_enum_map() {
+    let spec = build_enum_map_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"\x02404\0".as_ref(), &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("opcode"), Some(&Value::String("OP_REPLY".to_string())));
+    assert_eq!(request_info.get_info("status"), Some(&Value::String("NOT_FOUND".to_string())));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), b"\x02404\0");
+
+    // unknown status is passed through while unknown opcode fails
+    let spec = build_enum_map_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"\x01500\0".as_ref(), &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("opcode"), Some(&Value::String("OP_QUERY".to_string())));
+    assert_eq!(request_info.get_info("status"), Some(&Value::String("500".to_string())));
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"\x07404\0".as_ref(), &spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { ref value, .. }) if value == "UnSignedNumber8(7)"), "expected invalid value error, but got {:?}", result.err());
+
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("opcode".to_string(), Value::String("OP_DELETE".to_string())).is_ok());
+    assert!(request_info.add_info("status".to_string(), Value::String("NOT_FOUND".to_string())).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn test_enum_map_pass_through() {
+    let build_spec = || new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("opcode".to_string()), false)
+        .expect_u8_enum(SpecName::NoName, false, vec![(1, "OP_QUERY".to_string())], UnmappedValue::PassThrough)
+        .build();
+
+    // unmapped value is written as is
+    let spec = build_spec();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("opcode".to_string(), Value::UnSignedNumber8(7)).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), b"\x07");
+
+    // unknown name can not be written by the inner spec
+    let spec = build_spec();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("opcode".to_string(), Value::String("OP_DELETE".to_string())).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { ref value, .. }) if value.contains("OP_DELETE")), "expected invalid value error, but got {:?}", result);
+}
+
+fn build_modbus_spec() -> ListSpec {
+    let request = new_spec_builder(SpecName::Name("request".to_string()))
+        .inline_value_follows(SpecName::Name("address".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("function".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("start".to_string()), false)
+        .expect_u16(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("count".to_string()), false)
+        .expect_u16(SpecName::NoName, false)
+        .build();
+    new_spec_builder(SpecName::NoName)
+        .expect_checksum(SpecName::Name("crc".to_string()), false, request, ChecksumAlgorithm::Crc16Modbus, Endianness::Little)
+        .build()
+}
+
+#[tokio::test]
+async fn test_checksum() {
+    assert_eq!(ChecksumAlgorithm::Crc16Modbus.compute(b"123456789"), 0x4B37);
+    assert_eq!(ChecksumAlgorithm::Crc32.compute(b"123456789"), 0xCBF4_3926);
+    assert_eq!(ChecksumAlgorithm::Sum8.compute(b"\xff\x02"), 0x01);
+
+    let data: &[u8] = b"\x01\x03\x00\x00\x00\x0a\xc5\xcd";
+    let spec = build_modbus_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("count"), Some(&Value::UnSignedNumber16(10)));
+    assert_eq!(request_info.get_info("crc"), Some(&Value::UnSignedNumber16(0xcdc5)));
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"\x01\x03\x00\x00\x00\x0b\xc5\xcd".as_ref(), &spec).await;
+    assert!(matches!(result, Err(ParserError::ChecksumMismatch { actual: 0xcdc5, .. })), "expected checksum mismatch, but got {:?}", result.err());
+
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("address".to_string(), Value::UnSignedNumber8(1)).is_ok());
+    assert!(request_info.add_info("function".to_string(), Value::UnSignedNumber8(3)).is_ok());
+    assert!(request_info.add_info("start".to_string(), Value::UnSignedNumber16(0)).is_ok());
+    assert!(request_info.add_info("count".to_string(), Value::UnSignedNumber16(10)).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+fn build_computed_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("length".to_string()), false)
+        .expect_u16_length_of(SpecName::NoName, false, "payload".to_string())
+        .inline_value_follows(SpecName::Name("tag".to_string()), false)
+        .expect_cstring(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("payload".to_string()), false)
+        .expect_bytes_with_length_from(SpecName::NoName, "length".to_string(), LengthEncoding::Binary(ValueType::UnSignedNumber16), false)
+        .build()
+}
+
+#[tokio::test]
+async fn test_computed_length() {
+    let data: &[u8] = b"\x00\x05data\0hello";
+    let spec = build_computed_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("length"), Some(&Value::UnSignedNumber16(5)));
+    assert_eq!(request_info.get_info("payload"), Some(&Value::U8Vec(b"hello".to_vec())));
+
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("tag".to_string(), Value::String("data".to_string())).is_ok());
+    assert!(request_info.add_info("payload".to_string(), Value::U8Vec(b"hello world".to_vec())).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), b"\x00\x0bdata\0hello world");
+
+    // stale length is reported instead of being written
+    let spec = build_computed_spec();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("length".to_string(), Value::UnSignedNumber16(99)).is_ok());
+    assert!(request_info.add_info("tag".to_string(), Value::String("data".to_string())).is_ok());
+    assert!(request_info.add_info("payload".to_string(), Value::U8Vec(b"hello world".to_vec())).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { ref value, .. }) if value == "UnSignedNumber16(99)"), "expected invalid value error, but got {:?}", result);
+}
+
+fn build_magic_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .expect_magic(b"\x89PNG")
+        .inline_value_follows(SpecName::Name("version".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .build()
+}
+
+#[tokio::test]
+async fn test_exact_bytes() {
+    let data: &[u8] = b"\x89PNG\x02";
+    let spec = build_magic_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("version"), Some(&Value::UnSignedNumber8(2)));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+
+    let spec = build_magic_spec();
+    let mut request_info = TestRequestInfo::new();
+    let result = deserialize_with(&mut request_info, b"\x89PNx\x02".as_slice(), &spec).await;
+    match result {
+        Err(ParserError::UnexpectedBytes { offset, expected, found, .. }) => {
+            assert_eq!(offset, 3);
+            assert_eq!(expected, b"\x89PNG".to_vec());
+            assert_eq!(found, b"\x89PNx".to_vec());
+        },
+        other => panic!("expected UnexpectedBytes error, but got {:?}", other.err()),
+    }
+}
+
+#[tokio::test]
+async fn test_alignment_and_padding() {
+    let data: &[u8] = b"\x01\x00\x00\x00\x00\x00\x00\x05\x00\x00\x00\x07";
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("tag".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .align_to(4)
+        .inline_value_follows(SpecName::Name("length".to_string()), false)
+        .expect_u32(SpecName::NoName, false)
+        .align_to(4)
+        .padding(2)
+        .inline_value_follows(SpecName::Name("flags".to_string()), false)
+        .expect_u16(SpecName::NoName, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("tag"), Some(&Value::UnSignedNumber8(1)));
+    assert_eq!(request_info.get_info("length"), Some(&Value::UnSignedNumber32(5)));
+    assert_eq!(request_info.get_info("flags"), Some(&Value::UnSignedNumber16(7)));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+fn build_padded_checksum_spec() -> ListSpec {
+    let covered = new_spec_builder(SpecName::Name("record".to_string()))
+        .inline_value_follows(SpecName::Name("tag".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .align_to(4)
+        .inline_value_follows(SpecName::Name("value".to_string()), false)
+        .expect_u16(SpecName::NoName, false)
+        .build();
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("version".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .expect_checksum(SpecName::Name("sum".to_string()), false, covered, ChecksumAlgorithm::Sum8, Endianness::Big)
+        .build()
+}
+
+#[tokio::test]
+async fn test_padding_inside_checksum() {
+    // alignment is relative to the start of the stream, not to the start of the covered bytes, and the
+    // padding bytes are part of the checksum
+    let data: &[u8] = b"\x01\x02\x00\x00\x00\x03\x05";
+    let spec = build_padded_checksum_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("value"), Some(&Value::UnSignedNumber16(3)));
+    assert_eq!(request_info.get_info("sum"), Some(&Value::UnSignedNumber8(5)));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+
+    let spec = build_padded_checksum_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"\x01\x02\x00\x00\x00\x03\x06".as_ref(), &spec).await;
+    assert!(matches!(result, Err(ParserError::ChecksumMismatch { expected: 5, actual: 6, .. })), "expected checksum mismatch, but got {:?}", result.err());
+
+    // a non zero padding byte is covered by the checksum
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"\x01\x02\x00\x01\x00\x03\x05".as_ref(), &spec).await;
+    assert!(matches!(result, Err(ParserError::ChecksumMismatch { expected: 6, actual: 5, .. })), "expected checksum mismatch, but got {:?}", result.err());
+}
+
+fn build_quoted_string_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("greeting".to_string()), false)
+        .expect_quoted_string(SpecName::NoName, false)
+        .expect_exact_string(SpecName::Delimiter, ";".to_string(), false)
+        .inline_value_follows(SpecName::Name("name".to_string()), false)
+        .expect_quoted_string_with(SpecName::NoName, b'\'', b'\'', false)
+        .build()
+}
+
+#[tokio::test]
+async fn test_quoted_string() {
+    let data: &[u8] = br#""say \"hi\" \\ bye";'o''brien'"#;
+    let spec = build_quoted_string_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("greeting"), Some(&Value::String(r#"say "hi" \ bye"#.to_string())));
+    assert_eq!(request_info.get_info("name"), Some(&Value::String("o'brien".to_string())));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+#[tokio::test]
+async fn test_unterminated_quoted_string() {
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("greeting".to_string()), false)
+        .expect_quoted_string(SpecName::NoName, false)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, br#""say hi"#, &spec).await;
+    assert!(matches!(result, Err(ParserError::MissingValue(_))), "expected missing value error, but got {:?}", result);
+
+    // escape at the end of stream does not escape anything
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, br#""say hi\"#, &spec).await;
+    assert!(matches!(result, Err(ParserError::MissingValue(_))), "expected missing value error, but got {:?}", result);
+
+    // doubled quote at the end of stream ends the string
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("name".to_string()), false)
+        .expect_quoted_string_with(SpecName::NoName, b'\'', b'\'', false)
+        .build();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"'o''brien'", &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("name"), Some(&Value::String("o'brien".to_string())));
+}
+
+fn build_any_of_delimiter_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("method".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_any_of(vec![" ".to_string(), "\t".to_string()])
+        .inline_value_follows(SpecName::Name("path".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_any_of(vec!["\r\n".to_string(), "\n".to_string()])
+        .inline_value_follows(SpecName::Name("host".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_any_of(vec!["\r\n".to_string(), "\n".to_string()])
+        .build()
+}
+
+#[tokio::test]
+async fn test_any_of_delimiters() {
+    let data: &[u8] = b"GET\t/index\nexample.com\r\n";
+    let spec = build_any_of_delimiter_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("method"), Some(&Value::String("GET".to_string())));
+    assert_eq!(request_info.get_info("path"), Some(&Value::String("/index".to_string())));
+    assert_eq!(request_info.get_info("host"), Some(&Value::String("example.com".to_string())));
+
+    // matched delimiters are reproduced
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+
+    // canonical delimiters are written when the data was not parsed
+    let spec = build_any_of_delimiter_spec();
+    let mut request_info = new_request_info(&spec);
+    assert!(request_info.add_info("method".to_string(), Value::String("GET".to_string())).is_ok());
+    assert!(request_info.add_info("path".to_string(), Value::String("/index".to_string())).is_ok());
+    assert!(request_info.add_info("host".to_string(), Value::String("example.com".to_string())).is_ok());
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), b"GET /index\r\nexample.com\r\n");
+}
+
+fn build_any_of_terminated_repeat_spec() -> ListSpec {
+    let line = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("line".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_any_of(vec!["\r\n".to_string(), "\n".to_string()])
+        .build();
+    new_spec_builder(SpecName::NoName)
+        .repeat_many(SpecName::Name("lines".to_string()), false, Separator::AnyOf(vec!["\r\n".to_string(), "\n".to_string()]), line)
+        .build()
+}
+
+#[tokio::test]
+async fn test_any_of_repeat_terminator() {
+    let data: &[u8] = b"first\r\nsecond\n\n";
+    let spec = build_any_of_terminated_repeat_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+
+    // matched terminator is reproduced
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+#[test]
+#[should_panic(expected = "at least one delimiter is expected")]
+fn test_empty_any_of_delimiters() {
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("line".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_any_of(vec![]);
+}
+
+fn build_text_number_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .expect_exact_string(SpecName::Delimiter, "$".to_string(), false)
+        .inline_value_follows(SpecName::Name("length".to_string()), false)
+        .expect_decimal(SpecName::NoName, ValueType::UnSignedNumber64, false)
+        .delimited_by_newline()
+        .inline_value_follows(SpecName::Name("chunk_size".to_string()), false)
+        .expect_hex(SpecName::NoName, ValueType::UnSignedNumber32, false)
+        .delimited_by_newline()
+        .inline_value_follows(SpecName::Name("offset".to_string()), false)
+        .expect_decimal(SpecName::NoName, ValueType::SignedNumber16, false)
+        .delimited_by_space()
+        .build()
+}
+
+#[tokio::test]
+async fn test_text_numbers() {
+    let data: &[u8] = b"$5\r\n1a\r\n-42 ";
+    let spec = build_text_number_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("length"), Some(&Value::UnSignedNumber64(5)));
+    assert_eq!(request_info.get_info("chunk_size"), Some(&Value::UnSignedNumber32(26)));
+    assert_eq!(request_info.get_info("offset"), Some(&Value::SignedNumber16(-42)));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+
+    let spec = build_text_number_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, b"$5x\r\n1a\r\n-42 ".as_slice(), &spec).await;
+    match result {
+        Err(ParserError::InvalidValue { value, .. }) => assert_eq!(value, "5x"),
+        other => panic!("expected InvalidValue error, but got {:?}", other.err()),
+    }
+}
+
+fn build_timestamp_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("date".to_string()), false)
+        .expect_timestamp(SpecName::NoName, TimestampFormat::ImfFixdate, false)
+        .delimited_by_newline()
+        .inline_value_follows(SpecName::Name("modified".to_string()), false)
+        .expect_timestamp(SpecName::NoName, TimestampFormat::Rfc3339, false)
+        .delimited_by_newline()
+        .inline_value_follows(SpecName::Name("created".to_string()), false)
+        .expect_epoch_timestamp(SpecName::NoName, EpochUnit::Seconds, ValueType::UnSignedNumber32, Endianness::Big, false)
+        .inline_value_follows(SpecName::Name("expires".to_string()), false)
+        .expect_epoch_timestamp(SpecName::NoName, EpochUnit::Millis, ValueType::UnSignedNumber64, Endianness::Little, false)
+        .build()
+}
+
+#[tokio::test]
+async fn test_timestamps() {
+    let data: &[u8] = b"Sun, 06 Nov 1994 08:49:37 GMT\r\n1994-11-06T08:49:37Z\r\n\x2e\xbc\x98\xa1\xe8\x34\xb4\x90\xb6\x00\x00\x00";
+    let expected = Value::Timestamp(DateTime::from_timestamp(784111777, 0).unwrap());
+    let spec = build_timestamp_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("date"), Some(&expected));
+    assert_eq!(request_info.get_info("modified"), Some(&expected));
+    assert_eq!(request_info.get_info("created"), Some(&expected));
+    assert_eq!(request_info.get_info("expires"), Some(&expected));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+fn build_address_spec() -> ListSpec {
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("a".to_string()), false)
+        .expect_ipv4(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("aaaa".to_string()), false)
+        .expect_ipv6(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("destination".to_string()), false)
+        .expect_address(SpecName::NoName, AddressKind::SocketV4, false)
+        .inline_value_follows(SpecName::Name("hardware".to_string()), false)
+        .expect_mac(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("source".to_string()), false)
+        .expect_text_address(SpecName::NoName, AddressKind::Ip, false)
+        .delimited_by_space()
+        .inline_value_follows(SpecName::Name("proxy".to_string()), false)
+        .expect_text_address(SpecName::NoName, AddressKind::Socket, false)
+        .delimited_by_newline()
+        .build()
+}
+
+#[tokio::test]
+async fn test_addresses() {
+    let data: &[u8] = b"\xc0\xa8\x00\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x0a\x00\x00\x01\x01\xbb\x00\x1a\x2b\x3c\x4d\x5e192.168.0.1 [2001:db8::1]:8080\r\n";
+    let spec = build_address_spec();
+    let mut request_info = new_request_info(&spec);
+    let result = deserialize_with(&mut request_info, data, &spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    assert_eq!(request_info.get_info("a"), Some(&Value::IpAddr("192.168.0.1".parse().unwrap())));
+    assert_eq!(request_info.get_info("aaaa"), Some(&Value::IpAddr("::1".parse().unwrap())));
+    assert_eq!(request_info.get_info("destination"), Some(&Value::SocketAddr("10.0.0.1:443".parse().unwrap())));
+    assert_eq!(request_info.get_info("hardware"), Some(&Value::MacAddr([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e])));
+    assert_eq!(request_info.get_info("source").and_then(|value| value.get_ip_addr_value()), Some("192.168.0.1".parse().unwrap()));
+    assert_eq!(request_info.get_info("proxy").and_then(|value| value.get_socket_addr_value()), Some("[2001:db8::1]:8080".parse().unwrap()));
+    assert_eq!(request_info.get_info("hardware").and_then(|value| value.get_string_value()), Some("00:1a:2b:3c:4d:5e".to_string()));
+
+    let result = serialize_with(&mut request_info, spec).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.as_ref().err());
+    assert_eq!(result.unwrap(), data);
+}
+
+fn build_tree_spec() -> ListSpec {
+    let request_line = new_spec_builder(SpecName::Name("request_line".to_string()))
+        .inline_value_follows(SpecName::Name("method".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_space()
+        .inline_value_follows(SpecName::Name("uri".to_string()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .build();
+    let header = new_spec_builder(SpecName::Transient("header".to_string()))
+        .key_follows(SpecName::Name("header_name".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by(": ".to_string())
+        .value_follows(SpecName::Name("header_value".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .build();
+    new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("id".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .expect_bit_fields(SpecName::Name("flags".to_string()), false, ValueType::UnSignedNumber8, Endianness::Big, vec![
+            BitField::new("version", 4), BitField::new("kind", 4),
+        ])
+        .expect_composite(request_line)
+        .repeat_many(SpecName::Name("headers".to_string()), false, Separator::Delimiter("\r\n".to_string()), header)
+        .build()
+}
+
+#[tokio::test]
+async fn test_composite_tree() {
+    let data: &[u8] = b"\x07\x12GET /index\r\nHost: localhost\r\nAccept: */*\r\n\r\n";
+    let spec = build_tree_spec();
+    let mut request_info = new_request_info(&spec);
+    let mut protocol_reader = ProtocolBuffReader::new(BufReader::new(data), 1024);
+    let result = spec.deserialize(&mut request_info, &mut protocol_reader, false).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    let tree = result.unwrap();
+    let root = tree.get_map_value().unwrap();
+    assert_eq!(root.get("id"), Some(&Value::UnSignedNumber8(7)));
+    assert_eq!(root.get("version"), Some(&Value::UnSignedNumber8(1)));
+    assert_eq!(root.get("kind"), Some(&Value::UnSignedNumber8(2)));
+    let request_line = root.get("request_line").and_then(|value| value.get_map_value()).unwrap();
+    assert_eq!(request_line.get("method"), Some(&Value::String("GET".to_string())));
+    assert_eq!(request_line.get("uri"), Some(&Value::String("/index".to_string())));
+    let headers = root.get("headers").and_then(|value| value.get_list_value()).unwrap();
+    assert_eq!(headers.len(), 2);
+    assert_eq!(headers[0].get_map_value().and_then(|header| header.get("Host")), Some(&Value::String("localhost".to_string())));
+    assert_eq!(headers[1].get_map_value().and_then(|header| header.get("Accept")), Some(&Value::String("*/*".to_string())));
+
+    // map has no byte encoding of its own
+    assert!(matches!(tree.to_bytes(Endianness::Big), Err(ParserError::InvalidValue { .. })));
+
+    // InfoProvider is not updated
+    assert_eq!(request_info.get_info("method"), None);
+    assert!(request_info.get_mapper().get_spec_data().is_empty());
+}
+
+#[tokio::test]
+async fn test_composite_tree_with_derived_fields() {
+    let data: &[u8] = b"\x00\x05hello\x02a: 1\r\nb: 2\r\n";
+    let entry = new_spec_builder(SpecName::Transient("entry".to_string()))
+        .key_follows(SpecName::Name("entry_name".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by(": ".to_string())
+        .value_follows(SpecName::Name("entry_value".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .build();
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("payload_length".to_string()), false)
+        .expect_u16(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("payload".to_string()), false)
+        .expect_string_with_length_from(SpecName::NoName, "payload_length".to_string(), LengthEncoding::Binary(ValueType::UnSignedNumber16), false)
+        .inline_value_follows(SpecName::Name("entry_count".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .repeat_with_count_from(SpecName::Name("entries".to_string()), false, "entry_count".to_string(), LengthEncoding::Binary(ValueType::UnSignedNumber8), entry)
+        .build();
+
+    let mut request_info = new_request_info(&spec);
+    let mut protocol_reader = ProtocolBuffReader::new(BufReader::new(data), 1024);
+    let result = spec.deserialize(&mut request_info, &mut protocol_reader, false).await;
+    assert!(result.is_ok(), "expected success, but got error {:?}", result.err());
+    let tree = result.unwrap();
+    let root = tree.get_map_value().unwrap();
+    assert_eq!(root.get("payload"), Some(&Value::String("hello".to_string())));
+    let entries = root.get("entries").and_then(|value| value.get_list_value()).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[1].get_map_value().and_then(|entry| entry.get("b")), Some(&Value::String("2".to_string())));
+    assert!(request_info.get_mapper().get_spec_data().is_empty());
+}
+
+#[tokio::test]
+async fn test_composite_tree_invalid_entries() {
+    // entry with the same name
+    let spec = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("id".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .inline_value_follows(SpecName::Name("id".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .build();
+    let mut request_info = new_request_info(&spec);
+    let mut protocol_reader = ProtocolBuffReader::new(BufReader::new(&b"\x01\x02"[..]), 1024);
+    let result = spec.deserialize(&mut request_info, &mut protocol_reader, false).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+
+    // list without name
+    let entry = new_spec_builder(SpecName::NoName)
+        .inline_value_follows(SpecName::Name("entry".to_string()), false)
+        .expect_u8(SpecName::NoName, false)
+        .build();
+    let spec = new_spec_builder(SpecName::NoName)
+        .repeat_n_times(SpecName::NoName, false, 2, entry)
+        .build();
+    let mut request_info = new_request_info(&spec);
+    let mut protocol_reader = ProtocolBuffReader::new(BufReader::new(&b"\x01\x02"[..]), 1024);
+    let result = spec.deserialize(&mut request_info, &mut protocol_reader, false).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+}
+
+#[tokio::test]
+async fn test_key_value_spec_name_is_not_mapped() {
+    let spec = new_spec_builder(SpecName::NoName)
+        .key_follows(SpecName::Name("name".to_owned()), false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by(": ".to_string())
+        .value_follows(SpecName::NoName, false)
+        .expect_string(SpecName::NoName, false)
+        .delimited_by_newline()
+        .build();
+    let mut request_info = new_request_info(&spec);
+
+    // name generated by the builder holds no protocol data, so it is not a lookup name of the unnamed value
+    assert_eq!(request_info.get_mapper().get_mapping_data_template().get("key-value-spec"), None);
+    let result = deserialize_with(&mut request_info, b"a: b\r\n", &spec).await;
+    assert!(matches!(result, Err(ParserError::InvalidValue { .. })), "expected invalid value error, but got {:?}", result);
+    assert_eq!(request_info.get_info("key-value-spec"), None);
+}
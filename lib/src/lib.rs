@@ -166,17 +166,20 @@ pub mod common{
     pub use crate::mapping_extractor::{SpecTraverse, traverse_spec, ToSpecType, DefaultMapper};
 
     pub use crate::core::{SpecMapper, Spec, SpecMetaData, MapperContext, ProtocolSpec, AllBytesSpec, 
-        DelimitedSpec, DelimitedStringSpec, OneOfSpec, NumberI16Spec, NumberI64Spec,
-        NumberU16Spec, NumberU32Spec, NumberU64Spec, ListSpec, SimpleValueSpec,RepeatManySpec, NBytesSpec, 
+        DelimitedSpec, ValueValidator, CharClass, DelimitedStringSpec, Radix, TextNumberSpec, TimestampFormat, TextTimestampSpec, EpochUnit, EpochTimestampSpec, AddressKind, AddressSpec, TextAddressSpec, CStringSpec, QuotedStringSpec, FixedWidthStringSpec, Alignment, OneOfSpec, NumberI16Spec, NumberI64Spec,
+        NumberU16Spec, NumberU32Spec, NumberU64Spec, NumberI32Spec, NumberU8Spec, NumberI8Spec,
+        NumberF32Spec, NumberF64Spec, BoolSpec, VarIntSpec, ZigZagVarIntSpec, LengthFromFieldSpec, LengthEncoding, ListSpec, SimpleValueSpec,RepeatManySpec, SwitchSpec, OneOfSpecs, BitFieldSpec, BitField, ChecksumSpec, ChecksumAlgorithm, ComputedSpec, ValueComputer, ByteLength, EnumMapSpec, ExactBytesSpec, Padding, PaddingSpec, UnmappedValue, NBytesSpec, 
         SpecRead, SpecWrite, Value, InfoProvider,
          Mapper, RequestInfo, ResponseInfo, ParserError, 
          RequestHandler, ResponseHandler, RequestFactory, ResponseFactory, RequestErrorHandler, ResponseErrorHandler, RequestSerializer, ResponseSerializer, DefaultSerializer,
-        ProtocolConfig,  Separator,
+        ProtocolConfig,  Separator, Endianness,
         SpecName, ValueType,  ValueExtractor, SpecSerialize, SpecDeserialize };
 
+    pub use crate::core::serde_bridge::{from_info_provider, to_info_provider, from_value, to_value};
+
         pub use crate::core::builders::{ProtoSpecBuilderData, BuildFromScratch,
         InlineValueBuilder, KeySpecBuilder, RepeatBuilder, DelimitedStringSpecBuilder, 
-        NumberSpecBuilder, DelimiterBuilder, ProtoSpecBuilder, ValueBuilder, CompositeBuilder, CustomSpecBuilder, StringSpecBuilder,
+        NumberSpecBuilder, LengthFromFieldSpecBuilder, BytesSpecBuilder, EnumMapBuilder, ComputedSpecBuilder, TextNumberSpecBuilder, TimestampSpecBuilder, AddressSpecBuilder, DelimiterBuilder, ProtoSpecBuilder, ValueBuilder, CompositeBuilder, SwitchBuilder, CustomSpecBuilder, StringSpecBuilder,
         new_mandatory_spec_builder};
 }
 
@@ -184,7 +187,7 @@ pub mod common{
 mod mapping_extractor{
     use std::collections::HashMap;
     use tracing::debug;
-    use crate::core::{extract_name_and_spec_path, InlineKeyWithValue, Key, KeyValueSpec, ListSpec, MappableSpec, Mapper, MapperContext, ParserError, RepeatManySpec, RepeaterContext, SimpleValueSpec, Spec, SpecMapper, SpecType, Value, ValueSpec};
+    use crate::core::{extract_name_and_spec_path, BitFieldSpec, ChecksumSpec, ExactBytesSpec, InlineKeyWithValue, PaddingSpec, Key, KeyValueSpec, ListSpec, MappableSpec, Mapper, MapperContext, OneOfSpecs, ParserError, RepeatManySpec, RepeaterContext, SimpleValueSpec, Spec, SpecMapper, SpecName, SpecType, SwitchSpec, Value, ValueSpec};
 
     pub trait SpecTraverse{
         fn traverse(&self, mapper: &mut dyn Mapper) -> Result<(), ParserError>;
@@ -195,6 +198,7 @@ mod mapping_extractor{
         protocol_to_spec_field_map: HashMap<String, String>,
         protocol_to_spec_template_map: HashMap<String, String>,
         spec_data_map: HashMap<String, Value>,
+        matched_delimiter_map: HashMap<String, String>,
         mapper_context: MapperContext,
         repeater_context_map: HashMap<String, RepeaterContext>,
         
@@ -212,6 +216,7 @@ mod mapping_extractor{
                 protocol_to_spec_field_map: HashMap::new(),
                 protocol_to_spec_template_map: HashMap::new(),
                 spec_data_map: HashMap::new(),
+                matched_delimiter_map: HashMap::new(),
                 mapper_context: MapperContext::new(),
                 repeater_context_map: HashMap::new(),
             }
@@ -244,6 +249,14 @@ mod mapping_extractor{
         &self.spec_data_map
     }
 
+    fn get_matched_delimiters_mut(&mut self) -> Option<&mut HashMap<String, String>> {
+        Some(&mut self.matched_delimiter_map)
+    }
+
+    fn get_matched_delimiters(&self) -> Option<&HashMap<String, String>> {
+        Some(&self.matched_delimiter_map)
+    }
+
     fn get_repeater_context_map_mut(&mut self) -> &mut HashMap<String, RepeaterContext>{
         &mut self.repeater_context_map
     }
@@ -287,6 +300,24 @@ mod mapping_extractor{
     impl ToSpecType for InlineKeyWithValue {
     }
 
+    impl ToSpecType for SwitchSpec {
+    }
+
+    impl ToSpecType for OneOfSpecs {
+    }
+
+    impl ToSpecType for BitFieldSpec {
+    }
+
+    impl ToSpecType for ChecksumSpec {
+    }
+
+    impl ToSpecType for ExactBytesSpec {
+    }
+
+    impl ToSpecType for PaddingSpec {
+    }
+
    impl ToSpecType for RepeatManySpec{
         fn to_spec_type(&self) ->SpecType{
             let spec_name = self.get_meta_data().get_name();
@@ -330,6 +361,42 @@ mod mapping_extractor{
         }
     }
 
+    impl SpecTraverse for SwitchSpec{
+        fn traverse(&self, mapper: &mut dyn Mapper) -> Result<(), ParserError> {
+            traverse_spec(self, mapper)
+        }
+    }
+
+    impl SpecTraverse for OneOfSpecs{
+        fn traverse(&self, mapper: &mut dyn Mapper) -> Result<(), ParserError> {
+            traverse_spec(self, mapper)
+        }
+    }
+
+    impl SpecTraverse for BitFieldSpec{
+        fn traverse(&self, mapper: &mut dyn Mapper) -> Result<(), ParserError> {
+            traverse_spec(self, mapper)
+        }
+    }
+
+    impl SpecTraverse for ChecksumSpec{
+        fn traverse(&self, mapper: &mut dyn Mapper) -> Result<(), ParserError> {
+            traverse_spec(self, mapper)
+        }
+    }
+
+    impl SpecTraverse for ExactBytesSpec{
+        fn traverse(&self, mapper: &mut dyn Mapper) -> Result<(), ParserError> {
+            traverse_spec(self, mapper)
+        }
+    }
+
+    impl SpecTraverse for PaddingSpec{
+        fn traverse(&self, mapper: &mut dyn Mapper) -> Result<(), ParserError> {
+            traverse_spec(self, mapper)
+        }
+    }
+
     //TODO change the return value to Result instead of unit
     pub fn traverse_spec<S>(spec: &S, mapper: &mut dyn Mapper) -> Result<(), ParserError> where S:MappableSpec + ?Sized{
         mapper.get_mapper_context_mut().start_spec_type(spec.to_spec_type());    
@@ -356,6 +423,62 @@ mod mapping_extractor{
         }
     }
 
+    impl SpecMapper for SwitchSpec{
+        fn add_mapping_template(&self, mapper: &mut dyn Mapper) ->Result<(), ParserError>  {
+            self.cases.iter()
+                .map(|(_, branch)| branch)
+                .chain(self.default_case.iter())
+                .try_for_each(|branch| branch.traverse(mapper))
+        }
+    }
+
+    impl SpecMapper for OneOfSpecs{
+        fn add_mapping_template(&self, mapper: &mut dyn Mapper) ->Result<(), ParserError>  {
+            // matched alternative is recorded using the name of the spec
+            if let SpecName::Name(name) = self.get_meta_data().get_name() {
+                let path = mapper.get_mapper_context().get_current_spec_path_template();
+                mapper.add_mapping_template(name.clone(), path);
+            }
+            self.alternatives.iter()
+                .try_for_each(|alternative| alternative.traverse(mapper))
+        }
+    }
+
+    impl SpecMapper for BitFieldSpec{
+        fn add_mapping_template(&self, mapper: &mut dyn Mapper) ->Result<(), ParserError>  {
+            // each field is stored under the path of this spec
+            let path = mapper.get_mapper_context().get_current_spec_path_template();
+            self.fields.iter()
+                .for_each(|field| mapper.add_mapping_template(field.name.clone(), format!("{}.{}", path, field.name)));
+            Ok(())
+        }
+    }
+
+    impl SpecMapper for ChecksumSpec{
+        fn add_mapping_template(&self, mapper: &mut dyn Mapper) ->Result<(), ParserError>  {
+            // checksum read is recorded using the name of the spec
+            if let SpecName::Name(name) = self.get_meta_data().get_name() {
+                let path = mapper.get_mapper_context().get_current_spec_path_template();
+                mapper.add_mapping_template(name.clone(), path);
+            }
+            self.covered.traverse(mapper)
+        }
+    }
+
+    impl SpecMapper for ExactBytesSpec{
+        fn add_mapping_template(&self, _mapper: &mut dyn Mapper) ->Result<(), ParserError>  {
+            // constant bytes are not stored
+            Ok(())
+        }
+    }
+
+    impl SpecMapper for PaddingSpec{
+        fn add_mapping_template(&self, _mapper: &mut dyn Mapper) ->Result<(), ParserError>  {
+            // padding bytes are not stored
+            Ok(())
+        }
+    }
+
     
     impl <T> SpecMapper for T where T:SimpleValueSpec{
         fn add_mapping_template(&self, mapper: &mut dyn Mapper)->Result<(), ParserError>  {
@@ -396,6 +519,10 @@ mod mapping_extractor{
             if let (Some(key_path), Some(value_path)) = (&key_spec_path, &value_spec_path) {
                 mapper.add_mapping_template(key_path.clone(), value_path.clone());
             }
+
+            if self.has_composite_value() {
+                self.value.traverse(mapper)?;
+            }
             Ok(())
         }
     }
@@ -437,10 +564,11 @@ pub mod core {
     
     
     
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     
+    use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
     use std::{
-        fmt::{Debug, Display, Formatter}, str::Utf8Error
+        fmt::{Debug, Display, Formatter}, net::{IpAddr, SocketAddr}, str::Utf8Error
     };
     use tokio::{
         io::{AsyncRead, AsyncWrite, AsyncWriteExt, BufReader},
@@ -500,6 +628,34 @@ pub mod core {
         IOError {
             error: std::io::Error,
         },
+
+        /// Value read or written for the spec is not valid for the spec
+        InvalidValue {
+            spec_path: String,
+            value: String,
+            message: String,
+        },
+
+        /// None of the alternatives of OneOfSpecs matched. Contains name and error of each alternative in the order they are tried
+        NoMatchingAlternative {
+            spec_name: String,
+            errors: Vec<(String, ParserError)>,
+        },
+
+        /// Checksum read does not match the checksum computed over the covered bytes
+        ChecksumMismatch {
+            spec_name: String,
+            expected: u32,
+            actual: u32,
+        },
+
+        /// Bytes read do not match the constant bytes of ExactBytesSpec. `offset` is the index of the first byte that differs
+        UnexpectedBytes {
+            spec_name: String,
+            offset: usize,
+            expected: Vec<u8>,
+            found: Vec<u8>,
+        },
     }
 
     impl From<std::io::Error> for ParserError {
@@ -578,11 +734,29 @@ impl From<serde_json::Error> for ParserError {
                 ParserError::InvalidMarker { line_index, char_index, message } => write!(
                     f,
                     "Invalid Marker provided during mark/reset operation at line {} char_pos {}: {}", line_index, char_index, message                       
-                ),                
+                ),
+                ParserError::InvalidValue { spec_path, value, message } => write!(
+                    f,
+                    "Invalid value {} for spec {}: {}", value, spec_path, message
+                ),
+                ParserError::NoMatchingAlternative { spec_name, errors } => {
+                    write!(f, "No alternative matched for spec {}", spec_name)?;
+                    errors.iter().try_for_each(|(alternative, error)| write!(f, "; {}: {}", alternative, error))
+                }
+                ParserError::ChecksumMismatch { spec_name, expected, actual } => write!(
+                    f,
+                    "Checksum mismatch for spec {}: expected {:#x}, found {:#x}", spec_name, expected, actual
+                ),
+                ParserError::UnexpectedBytes { spec_name, offset, expected, found } => write!(
+                    f,
+                    "Unexpected bytes for spec {} at offset {}: expected {:02x?}, found {:02x?}", spec_name, offset, expected, found
+                ),
             }
         }
     }
 
+    impl std::error::Error for ParserError {}
+
     #[allow(unused)]
     ///Extractor to retrieve underlying data from Value
     pub trait ValueExtractor<'a> {
@@ -608,6 +782,42 @@ impl From<serde_json::Error> for ParserError {
         /// Gets underlying u8 value  wrapped in Result
         fn get_u8_vec_unchecked(&self) -> Result<&Vec<u8>, ParserError>;
 
+        /// Gets underlying u8 number value  wrapped in Result
+        fn get_unsigned_num_8_value_unchecked(&self) -> Result<u8, ParserError>;
+
+        /// Gets underlying i8 value  wrapped in Result
+        fn get_signed_num_8_value_unchecked(&self) -> Result<i8, ParserError>;
+
+        /// Gets underlying i32 value  wrapped in Result
+        fn get_signed_num_32_value_unchecked(&self) -> Result<i32, ParserError>;
+
+        /// Gets underlying f32 value  wrapped in Result
+        fn get_float_32_value_unchecked(&self) -> Result<f32, ParserError>;
+
+        /// Gets underlying f64 value  wrapped in Result
+        fn get_float_64_value_unchecked(&self) -> Result<f64, ParserError>;
+
+        /// Gets underlying bool value  wrapped in Result
+        fn get_bool_value_unchecked(&self) -> Result<bool, ParserError>;
+
+        /// Gets underlying timestamp value  wrapped in Result
+        fn get_timestamp_value_unchecked(&self) -> Result<DateTime<Utc>, ParserError>;
+
+        /// Gets underlying ip address wrapped in Result
+        fn get_ip_addr_value_unchecked(&self) -> Result<IpAddr, ParserError>;
+
+        /// Gets underlying socket address wrapped in Result
+        fn get_socket_addr_value_unchecked(&self) -> Result<SocketAddr, ParserError>;
+
+        /// Gets underlying mac address wrapped in Result
+        fn get_mac_addr_value_unchecked(&self) -> Result<[u8; 6], ParserError>;
+
+        /// Gets underlying list of values wrapped in Result
+        fn get_list_value_unchecked(&self) -> Result<&Vec<Value>, ParserError>;
+
+        /// Gets underlying map of values wrapped in Result
+        fn get_map_value_unchecked(&self) -> Result<&HashMap<String, Value>, ParserError>;
+
         /// Gets underlying string value as Option
         fn get_string_value(&self) -> Option<String>;
 
@@ -628,6 +838,42 @@ impl From<serde_json::Error> for ParserError {
 
         /// Gets underlying data Vec<u8> Option
         fn get_u8_vec(&self) -> Option<&Vec<u8>>;
+
+        /// Gets underlying u8 value as Option
+        fn get_unsigned_num_8_value(&self) -> Option<u8>;
+
+        /// Gets underlying i8 value as Option
+        fn get_signed_num_8_value(&self) -> Option<i8>;
+
+        /// Gets underlying i32 value as Option
+        fn get_signed_num_32_value(&self) -> Option<i32>;
+
+        /// Gets underlying f32 value as Option
+        fn get_float_32_value(&self) -> Option<f32>;
+
+        /// Gets underlying f64 value as Option
+        fn get_float_64_value(&self) -> Option<f64>;
+
+        /// Gets underlying bool value as Option
+        fn get_bool_value(&self) -> Option<bool>;
+
+        /// Gets underlying timestamp value as Option. RFC 3339 strings are converted to timestamp
+        fn get_timestamp_value(&self) -> Option<DateTime<Utc>>;
+
+        /// Gets underlying ip address as Option. Address text is converted to ip address
+        fn get_ip_addr_value(&self) -> Option<IpAddr>;
+
+        /// Gets underlying socket address as Option. Address text is converted to socket address
+        fn get_socket_addr_value(&self) -> Option<SocketAddr>;
+
+        /// Gets underlying mac address as Option. Address text is converted to mac address
+        fn get_mac_addr_value(&self) -> Option<[u8; 6]>;
+
+        /// Gets underlying list of values as Option
+        fn get_list_value(&self) -> Option<&Vec<Value>>;
+
+        /// Gets underlying map of values as Option
+        fn get_map_value(&self) -> Option<&HashMap<String, Value>>;
     }
 
     impl ValueExtractor<'_> for Value {
@@ -639,6 +885,16 @@ impl From<serde_json::Error> for ParserError {
                 Value::UnSignedNumber64(ref data) => Some(data.to_string()),
                 Value::SignedNumber16(ref data) => Some(data.to_string()),
                 Value::SignedNumber64(ref data) => Some(data.to_string()),
+                Value::UnSignedNumber8(ref data) => Some(data.to_string()),
+                Value::SignedNumber8(ref data) => Some(data.to_string()),
+                Value::SignedNumber32(ref data) => Some(data.to_string()),
+                Value::Float32(ref data) => Some(data.to_string()),
+                Value::Float64(ref data) => Some(data.to_string()),
+                Value::Bool(ref data) => Some(data.to_string()),
+                Value::Timestamp(ref data) => Some(TimestampFormat::Rfc3339.format(data)),
+                Value::IpAddr(ref data) => Some(data.to_string()),
+                Value::SocketAddr(ref data) => Some(data.to_string()),
+                Value::MacAddr(ref data) => Some(data.iter().map(|octet| format!("{:02x}", octet)).collect::<Vec<_>>().join(":")),
 
                 _ => {
                     None
@@ -669,7 +925,7 @@ impl From<serde_json::Error> for ParserError {
         fn get_unsigned_num_32_value(&self) -> Option<u32> {
             match self {
                 Value::UnSignedNumber32(data) => Some(*data),
-                Value::String(data) => Some(data.parse::<u32>().unwrap()),
+                Value::String(data) => data.parse::<u32>().ok(),
 
                 _ => {
                     None
@@ -705,6 +961,60 @@ impl From<serde_json::Error> for ParserError {
                 }
             }
         }
+
+        fn get_unsigned_num_8_value(&self) -> Option<u8> {
+            match self {
+                Value::UnSignedNumber8(data) => Some(*data),
+                _ => {
+                    None
+                }
+            }
+        }
+
+        fn get_signed_num_8_value(&self) -> Option<i8> {
+            match self {
+                Value::SignedNumber8(data) => Some(*data),
+                _ => {
+                    None
+                }
+            }
+        }
+
+        fn get_signed_num_32_value(&self) -> Option<i32> {
+            match self {
+                Value::SignedNumber32(data) => Some(*data),
+                _ => {
+                    None
+                }
+            }
+        }
+
+        fn get_float_32_value(&self) -> Option<f32> {
+            match self {
+                Value::Float32(data) => Some(*data),
+                _ => {
+                    None
+                }
+            }
+        }
+
+        fn get_float_64_value(&self) -> Option<f64> {
+            match self {
+                Value::Float64(data) => Some(*data),
+                _ => {
+                    None
+                }
+            }
+        }
+
+        fn get_bool_value(&self) -> Option<bool> {
+            match self {
+                Value::Bool(data) => Some(*data),
+                _ => {
+                    None
+                }
+            }
+        }
         
         fn get_string_value_unchecked(&self) -> Result<String, ParserError> {
             match self.get_string_value(){
@@ -754,43 +1064,218 @@ impl From<serde_json::Error> for ParserError {
                 None => Err(ParserError::MissingValue(format!("unable to get vec of bytes value from {:?}", self)))
             }
         }
+
+        fn get_unsigned_num_8_value_unchecked(&self) -> Result<u8, ParserError> {
+            match self.get_unsigned_num_8_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get unsigned 8 value from {:?}", self)))
+            }
+        }
+
+        fn get_signed_num_8_value_unchecked(&self) -> Result<i8, ParserError> {
+            match self.get_signed_num_8_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get signed 8 value from {:?}", self)))
+            }
+        }
+
+        fn get_signed_num_32_value_unchecked(&self) -> Result<i32, ParserError> {
+            match self.get_signed_num_32_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get signed 32 value from {:?}", self)))
+            }
+        }
+
+        fn get_float_32_value_unchecked(&self) -> Result<f32, ParserError> {
+            match self.get_float_32_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get f32 value from {:?}", self)))
+            }
+        }
+
+        fn get_float_64_value_unchecked(&self) -> Result<f64, ParserError> {
+            match self.get_float_64_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get f64 value from {:?}", self)))
+            }
+        }
+
+        fn get_bool_value_unchecked(&self) -> Result<bool, ParserError> {
+            match self.get_bool_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get bool value from {:?}", self)))
+            }
+        }
+
+        fn get_timestamp_value(&self) -> Option<DateTime<Utc>> {
+            match self {
+                Value::Timestamp(data) => Some(*data),
+                Value::String(data) => TimestampFormat::Rfc3339.parse(data),
+                _ => None,
+            }
+        }
+
+        fn get_timestamp_value_unchecked(&self) -> Result<DateTime<Utc>, ParserError> {
+            match self.get_timestamp_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get timestamp value from {:?}", self)))
+            }
+        }
+
+        fn get_ip_addr_value(&self) -> Option<IpAddr> {
+            match self {
+                Value::IpAddr(data) => Some(*data),
+                Value::String(data) => data.parse().ok(),
+                _ => None,
+            }
+        }
+
+        fn get_ip_addr_value_unchecked(&self) -> Result<IpAddr, ParserError> {
+            match self.get_ip_addr_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get ip address from {:?}", self)))
+            }
+        }
+
+        fn get_socket_addr_value(&self) -> Option<SocketAddr> {
+            match self {
+                Value::SocketAddr(data) => Some(*data),
+                Value::String(data) => data.parse().ok(),
+                _ => None,
+            }
+        }
+
+        fn get_socket_addr_value_unchecked(&self) -> Result<SocketAddr, ParserError> {
+            match self.get_socket_addr_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get socket address from {:?}", self)))
+            }
+        }
+
+        fn get_mac_addr_value(&self) -> Option<[u8; 6]> {
+            match AddressKind::Mac.address_of(self) {
+                Some(Value::MacAddr(data)) => Some(data),
+                _ => None,
+            }
+        }
+
+        fn get_mac_addr_value_unchecked(&self) -> Result<[u8; 6], ParserError> {
+            match self.get_mac_addr_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get mac address from {:?}", self)))
+            }
+        }
+
+        fn get_list_value(&self) -> Option<&Vec<Value>> {
+            match self {
+                Value::List(data) => Some(data),
+                _ => None,
+            }
+        }
+
+        fn get_list_value_unchecked(&self) -> Result<&Vec<Value>, ParserError> {
+            match self.get_list_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get list value from {:?}", self)))
+            }
+        }
+
+        fn get_map_value(&self) -> Option<&HashMap<String, Value>> {
+            match self {
+                Value::Map(data) => Some(data),
+                _ => None,
+            }
+        }
+
+        fn get_map_value_unchecked(&self) -> Result<&HashMap<String, Value>, ParserError> {
+            match self.get_map_value(){
+                Some(data) => Ok(data),
+                None => Err(ParserError::MissingValue(format!("unable to get map value from {:?}", self)))
+            }
+        }
     }
     
 
-    /// Value wraps underlying data. It is wrapper for string, number and bytes 
+    /// Value wraps underlying data. It is wrapper for string, number and bytes. List and Map hold the values of 
+    /// composite specs when they are deserialized without updating the InfoProvider
     #[allow(unused)]
-    #[derive(Debug, Clone)]
+    #[derive(Debug, Clone, PartialEq)]
     pub enum Value {
         String(String),
         SignedNumber64(i64),
         UnSignedNumber64(u64),
         UnSignedNumber32(u32),
+        SignedNumber32(i32),
         SignedNumber16(i16),
         UnSignedNumber16(u16),
+        UnSignedNumber8(u8),
+        SignedNumber8(i8),
+        Float32(f32),
+        Float64(f64),
+        Bool(bool),
         U8Vec(Vec<u8>),
+        Timestamp(DateTime<Utc>),
+        IpAddr(IpAddr),
+        SocketAddr(SocketAddr),
+        MacAddr([u8; 6]),
+        List(Vec<Value>),
+        Map(HashMap<String, Value>),
         
         None,
     }
 
     /// Value Type enum list various types used to represent underlying protocol data 
-    #[derive(PartialEq)]
+    #[derive(PartialEq, Clone, Debug)]
     pub enum ValueType {
         String,
         SignedNumber64,
         UnSignedNumber64,
         UnSignedNumber32,
+        SignedNumber32,
         SignedNumber16,
         UnSignedNumber16,
+        UnSignedNumber8,
+        SignedNumber8,
+        Float32,
+        Float64,
+        Bool,
         U8Vec,
+        Timestamp,
+        IpAddr,
+        SocketAddr,
+        MacAddr,
         None,
         CompositeMap,
         CompositeList
     }
 
+    /// Byte order used to encode and decode binary numbers. Network protocols are mostly big-endian,
+    /// hence `Big` is the default
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum Endianness {
+        #[default]
+        Big,
+        Little,
+    }
+
     impl ValueType{
 
-        /// Converts slice into Value based on value type
-        pub fn parse(value_type: &ValueType, value: &[u8]) -> Value {                
+        /// Converts slice into Value based on value type. Numbers are decoded as big-endian
+        pub fn parse(value_type: &ValueType, value: &[u8]) -> Value {
+            Self::parse_with_endianness(value_type, value, Endianness::Big)
+        }
+
+        /// Converts slice into Value based on value type, decoding numbers using the given byte order
+        pub fn parse_with_endianness(value_type: &ValueType, value: &[u8], endianness: Endianness) -> Value {
+
+            macro_rules! from_bytes {
+                ($t:ty) => {
+                    match endianness {
+                        Endianness::Big => <$t>::from_be_bytes(value.try_into().unwrap()),
+                        Endianness::Little => <$t>::from_le_bytes(value.try_into().unwrap()),
+                    }
+                };
+            }
 
             match value_type {
                 ValueType::String => {
@@ -798,21 +1283,39 @@ impl From<serde_json::Error> for ParserError {
                                         }
                 
                 ValueType::SignedNumber64 => {
-                                            Value::SignedNumber64(i64::from_be_bytes(value.try_into().unwrap()))
+                                            Value::SignedNumber64(from_bytes!(i64))
                                         }
 
                 ValueType::UnSignedNumber64 => {
-                                            Value::UnSignedNumber64(u64::from_be_bytes(value.try_into().unwrap()))
+                                            Value::UnSignedNumber64(from_bytes!(u64))
                                         }   
                 ValueType::UnSignedNumber32 => {
-                    Value::UnSignedNumber32(u32::from_be_bytes(value.try_into().unwrap()))
+                    Value::UnSignedNumber32(from_bytes!(u32))
                     }
                 ValueType::SignedNumber16 => {
-                    Value::SignedNumber16(i16::from_be_bytes(value.try_into().unwrap()))
+                    Value::SignedNumber16(from_bytes!(i16))
                 }
                 ValueType::UnSignedNumber16 => {
-                    Value::UnSignedNumber16(u16::from_be_bytes(value.try_into().unwrap()))
+                    Value::UnSignedNumber16(from_bytes!(u16))
                 },
+                ValueType::SignedNumber32 => {
+                    Value::SignedNumber32(from_bytes!(i32))
+                }
+                ValueType::UnSignedNumber8 => {
+                    Value::UnSignedNumber8(from_bytes!(u8))
+                }
+                ValueType::SignedNumber8 => {
+                    Value::SignedNumber8(from_bytes!(i8))
+                }
+                ValueType::Float32 => {
+                    Value::Float32(from_bytes!(f32))
+                }
+                ValueType::Float64 => {
+                    Value::Float64(from_bytes!(f64))
+                }
+                ValueType::Bool => {
+                    Value::Bool(value[0] != 0)
+                }
                 ValueType::U8Vec => {
                                             Value::U8Vec(value.to_vec())
                                         }
@@ -848,17 +1351,96 @@ impl From<serde_json::Error> for ParserError {
             Value::UnSignedNumber16(num) => {
                 writer.write_u16(*num).await?;
             },
-            _ => todo!(),
+            Value::SignedNumber32(num) => {
+                writer.write_i32(*num).await?;
+            }
+            Value::UnSignedNumber8(num) => {
+                writer.write_u8(*num).await?;
+            }
+            Value::SignedNumber8(num) => {
+                writer.write_i8(*num).await?;
+            }
+            Value::Float32(num) => {
+                writer.write_f32(*num).await?;
+            }
+            Value::Float64(num) => {
+                writer.write_f64(*num).await?;
+            }
+            Value::Bool(flag) => {
+                writer.write_u8(*flag as u8).await?;
+            }
+            Value::Timestamp(data) => {
+                writer.write_all(TimestampFormat::Rfc3339.format(data).as_bytes()).await?;
+            }
+            Value::IpAddr(_) | Value::SocketAddr(_) | Value::MacAddr(_) | Value::List(_) | Value::Map(_) => {
+                writer.write_all(&value.to_bytes(Endianness::Big)?).await?;
+            }
+            // absent value has no bytes, like `Value::to_bytes`
+            Value::None => {}
         }
         Ok(())
     }
 
     impl Value {
 
-        /// Serializes Value to a AsyncWrite
-        #[allow(unused)]
-        async fn write<W: AsyncWrite + Unpin>(& self, mut writer: W) -> Result<(), ParserError> {
-            match self {
+        /// Encodes Value into bytes, numbers are encoded using the given byte order. Map can not be encoded as its entries
+        /// are unordered, entries of a map are encoded only through the specs of its entries
+        pub fn to_bytes(&self, endianness: Endianness) -> Result<Vec<u8>, ParserError> {
+
+            macro_rules! to_bytes {
+                ($num:expr) => {
+                    match endianness {
+                        Endianness::Big => $num.to_be_bytes().to_vec(),
+                        Endianness::Little => $num.to_le_bytes().to_vec(),
+                    }
+                };
+            }
+
+            let bytes = match self {
+                Value::String(s) => s.as_bytes().to_vec(),
+                Value::SignedNumber64(num) => to_bytes!(num),
+                Value::UnSignedNumber64(num) => to_bytes!(num),
+                Value::UnSignedNumber32(num) => to_bytes!(num),
+                Value::SignedNumber16(num) => to_bytes!(num),
+                Value::UnSignedNumber16(num) => to_bytes!(num),
+                Value::SignedNumber32(num) => to_bytes!(num),
+                Value::UnSignedNumber8(num) => vec![*num],
+                Value::SignedNumber8(num) => to_bytes!(num),
+                Value::Float32(num) => to_bytes!(num),
+                Value::Float64(num) => to_bytes!(num),
+                Value::Bool(flag) => vec![*flag as u8],
+                Value::U8Vec(data) => data.clone(),
+                Value::Timestamp(data) => TimestampFormat::Rfc3339.format(data).into_bytes(),
+                Value::IpAddr(IpAddr::V4(address)) => address.octets().to_vec(),
+                Value::IpAddr(IpAddr::V6(address)) => address.octets().to_vec(),
+                // addresses are always in network byte order
+                Value::SocketAddr(address) => {
+                    let mut data = Value::IpAddr(address.ip()).to_bytes(endianness)?;
+                    data.extend_from_slice(&address.port().to_be_bytes());
+                    data
+                },
+                Value::MacAddr(data) => data.to_vec(),
+                Value::List(values) => {
+                    let mut data = vec![];
+                    for value in values {
+                        data.extend(value.to_bytes(endianness)?);
+                    }
+                    data
+                },
+                Value::Map(_) => return Err(ParserError::InvalidValue {
+                    spec_path: String::new(),
+                    value: format!("{:?}", self),
+                    message: "map can not be encoded into bytes".to_string(),
+                }),
+                Value::None => vec![],
+            };
+            Ok(bytes)
+        }
+
+        /// Serializes Value to a AsyncWrite
+        #[allow(unused)]
+        async fn write<W: AsyncWrite + Unpin>(& self, mut writer: W) -> Result<(), ParserError> {
+            match self {
                 Value::String(s) => {
                                             writer.write_all(s.as_bytes()).await?;
                                         }
@@ -880,7 +1462,31 @@ impl From<serde_json::Error> for ParserError {
                 Value::UnSignedNumber16(num) => {
                                 writer.write_u16(*num).await?;
                             },
-                Value::None => todo!(),
+                Value::SignedNumber32(num) => {
+                                writer.write_i32(*num).await?;
+                            }
+                Value::UnSignedNumber8(num) => {
+                                writer.write_u8(*num).await?;
+                            }
+                Value::SignedNumber8(num) => {
+                                writer.write_i8(*num).await?;
+                            }
+                Value::Float32(num) => {
+                                writer.write_f32(*num).await?;
+                            }
+                Value::Float64(num) => {
+                                writer.write_f64(*num).await?;
+                            }
+                Value::Bool(flag) => {
+                                writer.write_u8(*flag as u8).await?;
+                            }
+                Value::Timestamp(data) => {
+                                writer.write_all(TimestampFormat::Rfc3339.format(data).as_bytes()).await?;
+                            }
+                Value::IpAddr(_) | Value::SocketAddr(_) | Value::MacAddr(_) | Value::List(_) | Value::Map(_) => {
+                                writer.write_all(&self.to_bytes(Endianness::Big)?).await?;
+                            }
+                Value::None => {},
             }
             Ok(())
         }
@@ -1180,6 +1786,7 @@ impl From<serde_json::Error> for ParserError {
             let mut mapper_context = MapperContext::new();
             let mut protocol_writer = ProtocolBuffWriter::new(writer);
 
+            prepare(&spec, request_info, &mut mapper_context)?;
             serialize(&spec, request_info,  &mut protocol_writer, &mut mapper_context).await?;
             Ok(())
         }
@@ -1206,12 +1813,13 @@ impl From<serde_json::Error> for ParserError {
     {
         async fn serialize_to<W>(
             &self,
-            response_info: RESI,
+            mut response_info: RESI,
             writer: W,
             spec: &dyn ProtocolSpec,
         ) -> Result<(), ParserError> where W: AsyncWrite + Unpin + Send + Sync {
             let mut protocol_writer = ProtocolBuffWriter::new(writer);
             let mut mapper_context= MapperContext::new();
+            prepare(spec, &mut response_info, &mut mapper_context)?;
             serialize(spec, &response_info, &mut protocol_writer, &mut mapper_context).await?;
             Ok(())
         }
@@ -1653,6 +2261,29 @@ impl From<serde_json::Error> for ParserError {
         return serialier.serialize(info_provider, mapper_context, writer).await;
     }
 
+    /// prepares the spec `spec` and its constituents for serialization. Spec is added to mapper_context before the
+    /// preparation and removed after it
+    fn prepare<S>(spec: &S, info_provider: &mut ( dyn InfoProvider + Send + Sync ), 
+        mapper_context: &mut MapperContext) -> Result<(), ParserError>
+        where S: ProtocolSpec + ?Sized{
+        mapper_context.start_spec(spec);
+        let result = spec.prepare_serialize(info_provider, mapper_context);
+        let end_spec_result = mapper_context.end_spec(spec);
+        result?;
+        end_spec_result
+    }
+
+    /// Runs `f` with `mapper_context` as the mapper context of `info_provider`. This allows lookups by name during
+    /// preparation to resolve specs inside RepeatMany specs to the entry that is currently prepared
+    fn with_mapper_context<T, F>(info_provider: &mut ( dyn InfoProvider + Send + Sync ), 
+        mapper_context: &mut MapperContext, f: F) -> T
+        where F: FnOnce(&mut ( dyn InfoProvider + Send + Sync )) -> T{
+        std::mem::swap(info_provider.get_mapper_context(), mapper_context);
+        let result = f(info_provider);
+        std::mem::swap(info_provider.get_mapper_context(), mapper_context);
+        result
+    }
+
     /// SpecSerialize implementation of SpecSerializer.  current spec is added to mapper_context before serialization
     /// and is removed from mapper context after the spec is serialized
     #[async_trait]
@@ -1750,6 +2381,13 @@ impl From<serde_json::Error> for ParserError {
             info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
             writer: &mut dyn SpecWrite,
         ) -> Result<(), ParserError>;
+
+        /// invoked on the whole spec tree before serialization starts. Specs whose data is derived from other data
+        /// (e.g length of another field) populate `info_provider` here. Composite specs forward the call to their constituents
+        /// and `mapper_context` is maintained the same way as in `serialize`
+        fn prepare_serialize(&self, _info_provider: &mut ( dyn InfoProvider + Send + Sync ), _mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            Ok(())
+        }
         
     }
 
@@ -1815,6 +2453,10 @@ impl From<serde_json::Error> for ParserError {
             }
             return Ok(())
         }
+
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            prepare(&self.0, info_provider, mapper_context)
+        }
     }
 
     /// wrapper for spec deserializer. the `inner` field contains the underlying deserializer
@@ -1901,6 +2543,9 @@ impl From<serde_json::Error> for ParserError {
         /// String separator
         Delimiter(String),
 
+        /// Any of the string separators. First separator is the canonical one
+        AnyOf(Vec<String>),
+
         /// Byte serparator
         NBytes(u32),
 
@@ -1910,6 +2555,21 @@ impl From<serde_json::Error> for ParserError {
     }
 
 
+    impl Separator{
+        /// Returns the delimiter to be written for the spec at `spec_path`. For `AnyOf`, the delimiter matched during
+        /// deserialization is returned if available, otherwise the first delimiter
+        fn resolve_delimiter<'a>(&'a self, mapper: &'a dyn Mapper, spec_path: &str) -> Option<&'a str>{
+            match self {
+                Separator::Delimiter(delimiter) => Some(delimiter),
+                Separator::AnyOf(delimiters) => mapper.get_matched_delimiters().and_then(|matched_delimiters| matched_delimiters.get(spec_path))
+                    .filter(|matched| delimiters.contains(matched))
+                    .or(delimiters.first())
+                    .map(|delimiter| delimiter.as_str()),
+                Separator::NBytes(_) | Separator::EndOfStream => None,
+            }
+        }
+    }
+
     /// type to store metadata of spec. Metadata contains name of spec(SpecName), data type of value represented by spec
     /// and optionality flag
     #[derive( PartialEq)]
@@ -1967,6 +2627,82 @@ impl From<serde_json::Error> for ParserError {
     pub trait DelimitedSpec: SimpleValueSpec + Default{
         fn set_delimiter(&mut self, delimiter: Separator) ;
         fn get_delimiter(& self) -> &Separator;
+
+        /// Sets the validator used to check the value during deserialization and serialization.
+        /// Specs that do not store a validator ignore it
+        fn set_validator(&mut self, _validator: Box<dyn ValueValidator>){
+            warn!("validator is not supported by spec {}, value is not validated", self.get_meta_data().get_name());
+        }
+
+        /// Returns the validator of the spec, specs without validator support return None
+        fn get_validator(&self) -> Option<&dyn ValueValidator>{
+            None
+        }
+
+        /// Validates the `value` of the spec at `spec_path` using the validator if available
+        fn validate(&self, spec_path: &str, value: &Value) -> Result<(), ParserError>{
+            if let (Some(validator), Some(data)) = (self.get_validator(), value.get_string_value()) {
+                validator.validate(&data).map_err(|message| ParserError::InvalidValue {
+                    spec_path: spec_path.to_owned(),
+                    value: data,
+                    message,
+                })?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Validator hook for string value of DelimitedSpec e.g http token, digits, hex id. 
+    /// Any closure of type Fn(&str) -> Result<(), String> can be used as validator for custom patterns
+    pub trait ValueValidator: Send + Sync{
+
+        /// Returns error message if the value is not valid
+        fn validate(&self, value: &str) -> Result<(), String>;
+    }
+
+    impl <F> ValueValidator for F where F: Fn(&str) -> Result<(), String> + Send + Sync{
+        fn validate(&self, value: &str) -> Result<(), String> {
+            self(value)
+        }
+    }
+
+    /// Commonly used character classes. Value is valid if it is not empty and all characters belong to the class
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum CharClass{
+        /// ascii digits 0-9
+        Digits,
+
+        /// ascii hex digits 0-9, a-f, A-F
+        HexDigits,
+
+        /// ascii letters and digits
+        Alphanumeric,
+
+        /// token characters as defined in http RFC 9110 e.g header names and methods
+        HttpToken,
+    }
+
+    impl CharClass{
+        fn contains(&self, ch: char) -> bool{
+            match self {
+                CharClass::Digits => ch.is_ascii_digit(),
+                CharClass::HexDigits => ch.is_ascii_hexdigit(),
+                CharClass::Alphanumeric => ch.is_ascii_alphanumeric(),
+                CharClass::HttpToken => ch.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(ch),
+            }
+        }
+    }
+
+    impl ValueValidator for CharClass{
+        fn validate(&self, value: &str) -> Result<(), String> {
+            if value.is_empty() {
+                return Err(format!("expected at least one character of {:?}", self));
+            }
+            match value.chars().find(|ch| !self.contains(*ch)) {
+                Some(ch) => Err(format!("character {:?} is not allowed in {:?}", ch, self)),
+                None => Ok(()),
+            }
+        }
     }
 
     /// trait to represent string spec
@@ -1981,6 +2717,7 @@ impl From<serde_json::Error> for ParserError {
     pub struct DelimitedStringSpec{
         spec_meta_data: SpecMetaData,
         until: Separator,
+        validator: Option<Box<dyn ValueValidator>>,
     }
 
 
@@ -2004,7 +2741,13 @@ impl From<serde_json::Error> for ParserError {
             &self.until
         }
 
-        
+        fn set_validator(&mut self, validator: Box<dyn ValueValidator>) {
+            self.validator = Some(validator);
+        }
+
+        fn get_validator(&self) -> Option<&dyn ValueValidator> {
+            self.validator.as_deref()
+        }
     }
 
     /// enum to represent the repeatcount for RepeatMany Spec. RepeatCount specifies when to stop the Repeat Count
@@ -2017,6 +2760,10 @@ impl From<serde_json::Error> for ParserError {
 
         /// Stop repatition when the delimiter is found
         Delimited(Separator),
+
+        /// Count is the value of previously parsed field with the given name e.g element count in a header.
+        /// The field is updated with the actual count of entries during serialization
+        FromField(String, LengthEncoding),
     }
 
     impl RepeatCount{
@@ -2024,15 +2771,19 @@ impl From<serde_json::Error> for ParserError {
         /// Serializer for RepeatCount
         async fn serialize (
             &self,
-            _info_provider: & ( dyn InfoProvider + Send + Sync ), _mapper_context: &mut MapperContext,
+            info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
             writer: &mut dyn SpecWrite,
         ) -> Result<(), ParserError>{
             match self {
                 
                 RepeatCount::Delimited(separator) => {
                     match separator{
-                        Separator::Delimiter(delimiter) => {
-                            writer.write_string(delimiter.to_owned()).await?;
+                        // terminator matched during deserialization is written to end the repetition, otherwise the first one
+                        Separator::Delimiter(_) | Separator::AnyOf(_) => {
+                            let terminator_path = repeat_terminator_path(mapper_context, true);
+                            if let Some(delimiter) = separator.resolve_delimiter(info_provider.get_mapper(), &terminator_path) {
+                                writer.write_string(delimiter.to_owned()).await?;
+                            }
                             Ok(())
                         },
                         Separator::NBytes(num) => {
@@ -2058,8 +2809,8 @@ impl From<serde_json::Error> for ParserError {
         }
     }
 
-    /// Repeat many spec struct.
-    /// Nested RepeatSpecs are not supported currently. constituents field cannot contain another repeat spec
+    /// Repeat many spec struct. constituents field can contain another repeat spec e.g list of parts where each part has a list of headers.
+    /// Data of specs inside repeat spec is stored with the index of each enclosing repeat spec e.g $.parts.1.part.headers.0.header.header_name
     #[derive(Default)]
     pub struct RepeatManySpec{        
         spec_meta_data: SpecMetaData,        
@@ -2074,6 +2825,62 @@ impl From<serde_json::Error> for ParserError {
     }
 
     
+    /// Gets the value of previously deserialized `field`. When InfoProvider is not updated, the field is resolved from the 
+    /// fields of the tree built so far
+    fn get_deserialized_field<'a>(info_provider: &'a (dyn InfoProvider + Send + Sync), field: &str, update_info: bool) -> Option<&'a Value>{
+        let tree_field = if update_info { None } else { info_provider.get_mapper().get_mapper_context().get_tree_field(field) };
+        tree_field.or_else(|| info_provider.get_info(field))
+    }
+
+    /// Returns the path under which the terminator of the current repetition is recorded e.g $.A.B.{} for RepeatMany spec B.
+    /// Root spec is not added to the mapper context during deserialization, hence it is skipped with `skip_root` during serialization
+    fn repeat_terminator_path(mapper_context: &MapperContext, skip_root: bool) -> String{
+        let types = mapper_context.types.get(usize::from(skip_root)..).unwrap_or_default();
+        let mut terminator_path = "$".to_owned();
+        for (index, spec_type) in types.iter().enumerate() {
+            let part = if index + 1 == types.len() { spec_type.to_path_template_string() } else { spec_type.to_path_string() };
+            terminator_path = format!("{}.{}", terminator_path, part);
+        }
+        terminator_path
+    }
+
+    /// Returns the number of consecutive entries, starting from the current index of the RepeatMany spec
+    /// in `mapper_context`, for which data is available. Spec data is scanned once for all entries
+    fn repeat_entry_count(info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &MapperContext) -> u32{
+        let current_path = mapper_context.get_current_spec_path();
+        let Some((repeat_path, current_index)) = current_path.rsplit_once('.') else {
+            return 0;
+        };
+        let Ok(current_index) = current_index.parse::<u32>() else {
+            return 0;
+        };
+        let entry_prefix = format!("{}.", repeat_path);
+        let indexes: HashSet<u32> = info_provider.get_mapper().get_spec_data().keys()
+            .filter_map(|key| key.strip_prefix(&entry_prefix))
+            .filter_map(|rest| rest.split_once('.'))
+            .filter_map(|(index, _)| index.parse().ok())
+            .collect();
+        (current_index..).take_while(|index| indexes.contains(index)).count() as u32
+    }
+
+    impl RepeatManySpec{
+
+        /// Gets the repeat count from the value of previously parsed field
+        fn get_count_from_field(&self, info_provider: &(dyn InfoProvider + Send + Sync), field: &str, update_info: bool) -> Result<u32, ParserError>{
+            match get_deserialized_field(info_provider, field, update_info) {
+                Some(value) => value_as_count(value).ok_or_else(|| ParserError::InvalidValue {
+                    spec_path: field.to_owned(),
+                    value: format!("{:?}", value),
+                    message: format!("count field of {} is not a valid count", self.get_meta_data().get_name().to_name_string()),
+                }),
+                None => Err(ParserError::MissingValue(format!(
+                    "count field {} is missing for repeat spec: {:?}",
+                    field, self.get_meta_data().get_name().to_name_string()
+                ))),
+            }
+        }
+    }
+
     /// Deserialize implementation for repeatmany.
     #[async_trait]
     impl SpecDeserialize for RepeatManySpec{
@@ -2083,23 +2890,37 @@ impl From<serde_json::Error> for ParserError {
             reader: &mut dyn SpecRead, update_info: bool,
         ) -> Result<Value, ParserError> {
             // Implementation for parsing repeat many spec
+            let expected_count = match &self.repeat_count{
+                RepeatCount::Fixed(count) => Some(*count),
+                RepeatCount::FromField(field, _) => Some(self.get_count_from_field(info_provider, field, update_info)?),
+                RepeatCount::Delimited(_) => None,
+            };
+            if expected_count == Some(0) {
+                return Ok(if update_info { Value::None } else { Value::List(vec![]) });
+            }
+
             let mut repeat_count = 0;
+            let mut values = vec![];
             loop{
 
                 // serialize the constituents
-                info_provider.get_mapper_context().increment_current_repeat_spec();
                 let result = self.constituents.deserialize(info_provider, reader, update_info).await;
-                if result.is_ok() {
+                if let Ok(ref value) = result {
                     repeat_count += 1;
+                    if !update_info {
+                        values.push(value.clone());
+                    }
                 }
+                info_provider.get_mapper_context().increment_current_repeat_spec();
 
                 //Check for ending the deserialization by RepeatMany Spec is delimiter is found ot repeat_count has reached its value
                 match &self.repeat_count{
-                    RepeatCount::Fixed(count) => {
-                        if result.is_err() && repeat_count < *count {
+                    RepeatCount::Fixed(_) | RepeatCount::FromField(_, _) => {
+                        let count = expected_count.unwrap_or_default();
+                        if result.is_err() && repeat_count < count {
                             return result;
                         }
-                        if repeat_count >= *count {
+                        if repeat_count >= count {
                             break;
                         }
                     },
@@ -2116,9 +2937,30 @@ impl From<serde_json::Error> for ParserError {
                                 }
                             },
 
+                            Separator::AnyOf(delimiters) => {
+                                // check if next few bytes matches any of the delimiters
+                                // longest delimiter is checked first e.g "\r\n" before "\r"
+                                let mut delimiters: Vec<&String> = delimiters.iter().collect();
+                                delimiters.sort_by_key(|delimiter| std::cmp::Reverse(delimiter.len()));
+                                let mut delimiter_found = false;
+                                for delimiter in delimiters {
+                                    let spec: Box<dyn ProtocolSpec> = Box::new(ExactStringSpec::new(SpecName::Delimiter, delimiter.clone(), false));
+                                    if undoable_deserialize(&spec, info_provider, reader, false).await.is_ok() {
+                                        let terminator_path = repeat_terminator_path(info_provider.get_mapper_context(), false);
+                                        insert_matched_delimiter(info_provider.get_mapper_mut(), terminator_path, delimiter.clone());
+                                        delimiter_found = true;
+                                        break;
+                                    }
+                                }
+                                if delimiter_found {
+                                    break;
+                                }
+                                let _ = result?;
+                            },
+
                             Separator::NBytes(n) => {
                                 // check if next few bytes matches the number
-                                let spec: Box<dyn ProtocolSpec> = Box::new(NumberU32Spec(SpecMetaData::new(SpecName::Delimiter, ValueType::UnSignedNumber32, false)));
+                                let spec: Box<dyn ProtocolSpec> = Box::new(NumberU32Spec(SpecMetaData::new(SpecName::Delimiter, ValueType::UnSignedNumber32, false), Endianness::Big));
                                 let number_read_result = undoable_deserialize(&spec, info_provider, reader, false).await;
                                 if let Ok(value) = number_read_result {
                                     if value.get_unsigned_num_32_value().unwrap() == *n {
@@ -2152,6 +2994,9 @@ impl From<serde_json::Error> for ParserError {
                     },
                 };
             }
+            if !update_info {
+                return Ok(Value::List(values));
+            }
             return Ok(Value::None);
             
             //// Return appropriate value based on parsing
@@ -2170,9 +3015,16 @@ impl From<serde_json::Error> for ParserError {
         ) -> Result<(), ParserError>
         {                        
             let mut has_one_success = false;
+            let entry_count = repeat_entry_count(info_provider, mapper_context);
+            let mut entry_index = 0;
             loop{
                 
-                let result = serialize(&self.constituents, info_provider, writer, mapper_context).await;
+                let result = if entry_index < entry_count {
+                    entry_index += 1;
+                    serialize(&self.constituents, info_provider, writer, mapper_context).await
+                }else{
+                    Err(ParserError::MissingData(format!("no data for repeat entry {}", mapper_context.get_current_spec_path())))
+                };
                 has_one_success |= result.is_ok();
                 if result.is_ok(){                    
                     mapper_context.increment_current_repeat_spec();
@@ -2192,6 +3044,26 @@ impl From<serde_json::Error> for ParserError {
                 }
             }
         }
+
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            // prepare repeat entries for which data is available
+            let count = repeat_entry_count(info_provider, mapper_context);
+            for _ in 0..count {
+                prepare(&self.constituents, info_provider, mapper_context)?;
+                mapper_context.increment_current_repeat_spec();
+            }
+            
+            if let RepeatCount::FromField(field, count_encoding) = &self.repeat_count {
+                let count_value = count_encoding.to_value(field, count as usize)?;
+                return with_mapper_context(info_provider, mapper_context, |info_provider| {
+                    if info_provider.get_info(field) != Some(&count_value) {
+                        info_provider.add_info(field.clone(), count_value)?;
+                    }
+                    Ok(())
+                });
+            }
+            Ok(())
+        }
     }
 
     ///Base trait Spec which is implemented by all specs. Spec contains metadata
@@ -2236,6 +3108,10 @@ impl From<serde_json::Error> for ParserError {
         ) -> Result<(), ParserError>{
             (**self).serialize(info_provider, mapper_context, writer).await
         }
+
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            (**self).prepare_serialize(info_provider, mapper_context)
+        }
     }
 
     /// SpecDeserialize implementation for box that forwards to underlying trait object
@@ -2312,11 +3188,64 @@ impl From<serde_json::Error> for ParserError {
     #[derive(Clone, Debug)]
     pub struct MapperContext{
         types: Vec<SpecType>,
+        changes: Vec<MapperChange>,
+        recording_depth: usize,
+        tree_fields: HashMap<String, Value>,
     }    
 
+    /// Change made to mapper data while changes are recorded, holds the previous value of the changed entry
+    #[derive(Clone, Debug)]
+    pub(crate) enum MapperChange{
+        SpecData(String, Option<Value>),
+        MappingData(String, Option<String>),
+        MappingTemplate(String, Option<String>),
+        MatchedDelimiter(String, Option<String>),
+        RepeaterContext(String, Option<RepeaterContext>),
+    }
+
     impl MapperContext{
         pub fn new() -> MapperContext{
-            Self { types: vec!() }
+            Self { types: vec!(), changes: vec!(), recording_depth: 0, tree_fields: HashMap::new() }
+        }
+
+        /// Records `value` of `field` deserialized without updating the InfoProvider, so that lengths, counts and discriminators
+        /// of the specs that follow can be resolved from it
+        pub(crate) fn add_tree_field(&mut self, field: String, value: Value){
+            self.tree_fields.insert(field, value);
+        }
+
+        /// Gets the value of `field` deserialized without updating the InfoProvider
+        pub(crate) fn get_tree_field(&self, field: &str) -> Option<&Value>{
+            self.tree_fields.get(field)
+        }
+
+        /// Starts recording changes made to mapper data. Returns the savepoint to undo the changes made after this call
+        pub(crate) fn start_recording(&mut self) -> usize{
+            self.recording_depth += 1;
+            self.changes.len()
+        }
+
+        /// Stops the recording started last. Changes are kept as long as an outer recording is active
+        pub(crate) fn stop_recording(&mut self){
+            self.recording_depth = self.recording_depth.saturating_sub(1);
+            if self.recording_depth == 0 {
+                self.changes.clear();
+            }
+        }
+
+        pub(crate) fn is_recording(&self) -> bool{
+            self.recording_depth > 0
+        }
+
+        pub(crate) fn record(&mut self, change: MapperChange){
+            if self.is_recording() {
+                self.changes.push(change);
+            }
+        }
+
+        /// Removes and returns the changes recorded after `savepoint`
+        pub(crate) fn take_changes_since(&mut self, savepoint: usize) -> Vec<MapperChange>{
+            self.changes.split_off(savepoint.min(self.changes.len()))
         }
 
         /// Adds given spec ojbect to spec types
@@ -2358,6 +3287,14 @@ impl From<serde_json::Error> for ParserError {
             Ok(())
         }
 
+        /// Returns the current index of each RepeatMany spec leading upto the current spec, outermost first
+        pub fn get_repeat_indexes(&self) -> Vec<u32>{
+            self.types.iter().filter_map(|spec_type| match spec_type{
+                SpecType::RepeatMany(_, _, current_index) => Some(*current_index as u32),
+                _ => None,
+            }).collect()
+        }
+
         /// Increments the repeat index in the last known repeatspec
         pub fn increment_current_repeat_spec(&mut self){
             let last = self.types.last_mut();
@@ -2421,14 +3358,25 @@ impl From<serde_json::Error> for ParserError {
     }
 
 
-    /// Converts repeater template to repeater path string. e.g spec_name is A.B.{}.C returns A.B.1.C. here 1 is the current index
-    fn normalize_repeater(spec_name: &str, repeater_context: &RepeaterContext,) -> String{
-        normalize_repeater_with_count(spec_name, repeater_context.get_count())        
+    /// Converts repeater template to repeater path string. e.g spec_name is A.{}.B.{}.C and indexes are [1, 2] returns A.1.B.2.C.
+    /// Placeholders are filled from the outermost repeater. Placeholders without a matching index are left as is
+    fn normalize_repeater(spec_name: &str, indexes: &[u32]) -> String{
+        let mut parts = spec_name.split("{}");
+        let mut path = parts.next().unwrap_or_default().to_owned();
+        let mut indexes = indexes.iter();
+        for part in parts{
+            match indexes.next(){
+                Some(index) => path.push_str(&index.to_string()),
+                None => path.push_str("{}"),
+            }
+            path.push_str(part);
+        }
+        path
     }
 
-    /// Converts repeater template to repeater path string. e.g spec_name is A.B.{}.C returns A.B.1.C. here 1 is the current index
-    fn normalize_repeater_with_count(spec_name: &str, count: u32) -> String{
-        spec_name.replace("{}", count.to_string().as_str())
+    /// Returns the number of repeater placeholders in the template
+    fn repeater_depth(spec_name: &str) -> usize{
+        spec_name.matches("{}").count()
     }
     /// Removes the `lookup_name` from the qualified name
     fn get_context_from_qualified_name(qualified_name:&str, lookup_name: &str)->String{
@@ -2438,12 +3386,19 @@ impl From<serde_json::Error> for ParserError {
     ///Mapper trait contains method to add and retrieve information from Mapper object
     pub trait Mapper:  Send + Sync + Debug{
 
-        /// Gets the Some(value) by `spec_name`. Returns None if the `spec_name` is not recognized
+        /// Gets the Some(value) by `spec_name`. Returns None if the `spec_name` is not recognized.
+        /// Specs inside RepeatMany specs are looked up using the current repeat indexes of the mapper context
         fn get_value_by_key(&self, spec_name: &str) -> Option<&Value>{
+            self.get_value_by_key_at(spec_name, &self.get_mapper_context().get_repeat_indexes())
+        }
+
+        /// Gets the Some(value) by `spec_name` for the given repeat indexes(outermost first) of enclosing RepeatMany specs
+        fn get_value_by_key_at(&self, spec_name: &str, repeat_indexes: &[u32]) -> Option<&Value>{
             let value_path = self.get_mapping_data_template().get(spec_name);            
             if let Some(value_path) = value_path{
+                let value_path = normalize_repeater(value_path, repeat_indexes);
                 debug!("getting value for key {} -> {}", spec_name, value_path);
-                self.get_spec_data().get(value_path)
+                self.get_spec_data().get(&value_path)
             }else{
                 None
             }
@@ -2452,11 +3407,19 @@ impl From<serde_json::Error> for ParserError {
         /// Gets the Some(value) by `spec_name` and key.This is used for querying information from RepeatMany key-value pairs
         /// Spec_name could be header_name and key could be 'Content-Type' in http headers example
         fn get_value_from_key_value_list(&self,key: String, spec_name: &str) -> Option<&Value>{
+            let repeat_indexes = self.get_mapper_context().get_repeat_indexes();
+            self.get_value_from_key_value_list_at(key, spec_name, &repeat_indexes)
+        }
+
+        /// Gets the Some(value) by `spec_name` and key from key-value list of nested RepeatMany spec.
+        /// `outer_repeat_indexes` are the indexes(outermost first) of RepeatMany specs enclosing the key-value list
+        fn get_value_from_key_value_list_at(&self,key: String, spec_name: &str, outer_repeat_indexes: &[u32]) -> Option<&Value>{
             let spec_path = self.get_mapping_data_template().get(spec_name);
             
             if let Some(spec_path) = spec_path{
                 debug!("getting value for key {} -> {}", spec_name, spec_path);
-                let key_quick_lookup_name = format!("{}.{}", spec_path, key);
+                let outer_depth = repeater_depth(spec_path).saturating_sub(1).min(outer_repeat_indexes.len());
+                let key_quick_lookup_name = format!("{}.{}", normalize_repeater(spec_path, &outer_repeat_indexes[..outer_depth]), key);
                 let value_path = self.get_mapping_data_template().get(&key_quick_lookup_name);
                 if let Some(value_path) = value_path{
                     self.get_spec_data().get(value_path)
@@ -2483,11 +3446,25 @@ impl From<serde_json::Error> for ParserError {
             
         }
 
-        /// Add data into mapper using simple key and value
+        /// Gets the path where value of `spec_name` is stored for the current repeat indexes of the mapper context
+        fn get_value_path(&self, spec_name: &str) -> Option<String>{
+            self.get_mapping_data_template().get(spec_name)
+                .map(|template| normalize_repeater(template, &self.get_mapper_context().get_repeat_indexes()))
+        }
+
+        /// Add data into mapper using simple key and value. 
+        /// Specs inside RepeatMany specs are added using the current repeat indexes of the mapper context
         fn add_simple_data(&mut self, key: String, value: Value) -> Result<(), ParserError>{            
+            let repeat_indexes = self.get_mapper_context().get_repeat_indexes();
+            self.add_simple_data_at(key, value, &repeat_indexes)
+        }
+
+        /// Add data into mapper using simple key and value for the given repeat indexes(outermost first) of enclosing RepeatMany specs
+        fn add_simple_data_at(&mut self, key: String, value: Value, repeat_indexes: &[u32]) -> Result<(), ParserError>{            
             if let Some(template) = self.get_mapping_data_template().get(&key).map(|element| element.to_owned()) {
-                debug!("adding value for key {} -> {}", key, template);
-                self.get_spec_data_mut().insert(template, value);
+                let spec_path = normalize_repeater(&template, repeat_indexes);
+                debug!("adding value for key {} -> {}", key, spec_path);
+                insert_spec_data(self, spec_path, value);
                 Ok(())
             }else{
                 Err(ParserError::MissingKey(format!("template lookup failed for key {}", key)))
@@ -2497,25 +3474,44 @@ impl From<serde_json::Error> for ParserError {
         /// Add data into mapper using key, key_lookup_name, value and value_lookup_name
         /// In http example key could be Content-Type, key_lookup_name could be `header_name` and value_lookup_name could be 'header_value`
         fn add_to_key_value_list(&mut self, key: String, value: Value, key_lookup_name: String, value_lookup_name: String) -> Result<(), ParserError>{
+            let repeat_indexes = self.get_mapper_context().get_repeat_indexes();
+            self.add_to_key_value_list_at(key, value, key_lookup_name, value_lookup_name, &repeat_indexes)
+        }
+
+        /// Add data into key-value list of nested RepeatMany spec. `outer_repeat_indexes` are the indexes(outermost first) of RepeatMany specs 
+        /// enclosing the key-value list. e.g index of multipart part containing the header list
+        fn add_to_key_value_list_at(&mut self, key: String, value: Value, key_lookup_name: String, value_lookup_name: String, outer_repeat_indexes: &[u32]) -> Result<(), ParserError>{
             
             let key_spec_name = self.get_qualified_name(&key_lookup_name)?;
             let value_spec_name = self.get_qualified_name(&value_lookup_name)?;
             
+            let outer_depth = repeater_depth(&key_spec_name).saturating_sub(1);
+            if outer_repeat_indexes.len() < outer_depth {
+                return Err(ParserError::MissingData(format!("repeat indexes of enclosing repeat specs missing for key {}", key_lookup_name)));
+            }
+            let outer_repeat_indexes = &outer_repeat_indexes[..outer_depth];
+            let key_spec_name = normalize_repeater(&key_spec_name, outer_repeat_indexes);
+            let value_spec_name = normalize_repeater(&value_spec_name, outer_repeat_indexes);
+
             let context_name= get_context_from_qualified_name(key_spec_name.as_str(),  key_lookup_name.as_str());
+            if self.get_mapper_context().is_recording() {
+                let previous = self.get_repeater_context_map_mut().get(&context_name).cloned();
+                self.get_mapper_context_mut().record(MapperChange::RepeaterContext(context_name.clone(), previous));
+            }
             
             let repeater_context = self.get_repeater_context_mut(context_name.to_owned());
-            let normalized_key_spec_name = normalize_repeater(&key_spec_name, repeater_context);
-            let value_spec_name = normalize_repeater(&value_spec_name, repeater_context);                        
+            let normalized_key_spec_name = normalize_repeater(&key_spec_name, &[repeater_context.get_count()]);
+            let value_spec_name = normalize_repeater(&value_spec_name, &[repeater_context.get_count()]);                        
             repeater_context.next();
 
             // Map key spec name to value spec name e.g headers.0.HeaderName -> headers.0.HeaderValue
-            self.get_mapping_data_mut().insert(normalized_key_spec_name.clone(), value_spec_name.clone());
+            insert_mapping_data(self, normalized_key_spec_name.clone(), value_spec_name.clone());
 
             // Map key spec name to the actual key string headers.0.HeaderName -> Content-Type
-            self.get_spec_data_mut().insert(normalized_key_spec_name.clone(), Value::String(key.clone()));
+            insert_spec_data(self, normalized_key_spec_name.clone(), Value::String(key.clone()));
 
             // Map value spec name to the actual  value headers.0.HeaderValue -> application/json
-            self.get_spec_data_mut().insert(value_spec_name.clone(), value);
+            insert_spec_data(self, value_spec_name.clone(), value);
 
             let key_quick_lookup_name = format!("{}.{}", key_spec_name, key);
 
@@ -2523,14 +3519,14 @@ impl From<serde_json::Error> for ParserError {
             debug!("adding value for  {} -> {}", key_quick_lookup_name, value_spec_name);
 
             // Map key to value spec name for quick lookup of value e.g. headers.0.HeaderName.Content-Type -> headers.0.HeaderValue
-            self.get_mapping_data_template_mut().insert(key_quick_lookup_name, value_spec_name);
+            insert_mapping_template(self, key_quick_lookup_name, value_spec_name);
             Ok(())
         }
 
         /// add mapping template data, proto_name -> `protocol_version`, spec_name could be `$.request_line.protocol_version`
         fn add_mapping_template(&mut self, proto_name: String, spec_name: String) {
             debug!("adding template for name {} -> {}", proto_name, spec_name);
-            self.get_mapping_data_template_mut().insert(proto_name, spec_name.clone());
+            insert_mapping_template(self, proto_name, spec_name.clone());
         }
 
         /// Gets mutable reference to  hash map containing mapping data
@@ -2551,6 +3547,17 @@ impl From<serde_json::Error> for ParserError {
         /// Gets the shared reference to hash map containing spec data
         fn get_spec_data(&self) -> &HashMap<String, Value>;
 
+        /// Gets the mutable reference to hash map containing delimiters matched by specs delimited by `Separator::AnyOf`, keyed by value path.
+        /// Returns None if the mapper does not record matched delimiters, the first delimiter is written during serialization then
+        fn get_matched_delimiters_mut(&mut self) -> Option<&mut HashMap<String, String>>{
+            None
+        }
+
+        /// Gets the shared reference to hash map containing delimiters matched by specs delimited by `Separator::AnyOf`
+        fn get_matched_delimiters(&self) -> Option<&HashMap<String, String>>{
+            None
+        }
+
 
         /// Gets the mutable reference to repeater context 
         fn get_repeater_context_mut(&mut self, context_name: String) -> &mut RepeaterContext{
@@ -2568,12 +3575,86 @@ impl From<serde_json::Error> for ParserError {
         fn get_mapper_context(&self) -> &MapperContext;
     }    
 
+    /// Inserts `value` at `key` of `map` and records the previous value of the entry when mapper changes are recorded
+    fn insert_recorded<M, V>(mapper: &mut M, key: String, value: V, map: fn(&mut M) -> &mut HashMap<String, V>, change: fn(String, Option<V>) -> MapperChange)
+    where M: Mapper + ?Sized{
+        if mapper.get_mapper_context().is_recording() {
+            let previous = map(mapper).insert(key.clone(), value);
+            mapper.get_mapper_context_mut().record(change(key, previous));
+        }else{
+            map(mapper).insert(key, value);
+        }
+    }
+
+    fn insert_spec_data<M: Mapper + ?Sized>(mapper: &mut M, path: String, value: Value){
+        insert_recorded(mapper, path, value, |mapper| mapper.get_spec_data_mut(), MapperChange::SpecData);
+    }
+
+    fn insert_mapping_data<M: Mapper + ?Sized>(mapper: &mut M, key_path: String, value_path: String){
+        insert_recorded(mapper, key_path, value_path, |mapper| mapper.get_mapping_data_mut(), MapperChange::MappingData);
+    }
+
+    fn insert_mapping_template<M: Mapper + ?Sized>(mapper: &mut M, name: String, template: String){
+        insert_recorded(mapper, name, template, |mapper| mapper.get_mapping_data_template_mut(), MapperChange::MappingTemplate);
+    }
+
+    fn insert_matched_delimiter<M: Mapper + ?Sized>(mapper: &mut M, value_path: String, delimiter: String){
+        let Some(matched_delimiters) = mapper.get_matched_delimiters_mut() else {
+            return;
+        };
+        let previous = matched_delimiters.insert(value_path.clone(), delimiter);
+        if mapper.get_mapper_context().is_recording() {
+            mapper.get_mapper_context_mut().record(MapperChange::MatchedDelimiter(value_path, previous));
+        }
+    }
+
+    fn restore_entry<V>(map: &mut HashMap<String, V>, key: String, previous: Option<V>){
+        match previous {
+            Some(previous) => { map.insert(key, previous); },
+            None => { map.remove(&key); },
+        }
+    }
+
+    /// Reverts the changes made to mapper data after `savepoint`, latest change first
+    fn undo_changes(mapper: &mut dyn Mapper, savepoint: usize){
+        let changes = mapper.get_mapper_context_mut().take_changes_since(savepoint);
+        for change in changes.into_iter().rev() {
+            match change {
+                MapperChange::SpecData(key, previous) => restore_entry(mapper.get_spec_data_mut(), key, previous),
+                MapperChange::MappingData(key, previous) => restore_entry(mapper.get_mapping_data_mut(), key, previous),
+                MapperChange::MappingTemplate(key, previous) => restore_entry(mapper.get_mapping_data_template_mut(), key, previous),
+                MapperChange::MatchedDelimiter(key, previous) => if let Some(matched_delimiters) = mapper.get_matched_delimiters_mut() {
+                    restore_entry(matched_delimiters, key, previous)
+                },
+                MapperChange::RepeaterContext(key, previous) => restore_entry(mapper.get_repeater_context_map_mut(), key, previous),
+            }
+        }
+    }
+
     /// Parses delimited string from reader
-    async fn parse_delimited_string_spec<D:DelimitedSpec>(spec: &D, reader: &mut dyn SpecRead,) -> Result<Value, ParserError>{
+    async fn parse_delimited_string_spec<D:DelimitedSpec>(spec: &D, info_provider: &mut (dyn InfoProvider + Send + Sync), reader: &mut dyn SpecRead,) -> Result<Value, ParserError>{
+        let value = read_delimited_spec(spec, info_provider, reader).await?;
+        Ok(ValueType::parse(&spec.get_meta_data().value_type, &value))
+    }
+
+    /// Reads bytes of delimited spec from reader till the delimiter
+    async fn read_delimited_spec<D:DelimitedSpec>(spec: &D, info_provider: &mut (dyn InfoProvider + Send + Sync), reader: &mut dyn SpecRead,) -> Result<Vec<u8>, ParserError>{
         let value = match spec.get_delimiter() {
                 Separator::Delimiter(ref delimiter) => {
                     reader.read_placeholder_until(delimiter.to_owned()).await?
                 }
+                Separator::AnyOf(ref delimiters) => {
+                    let result = reader.read_placeholder_until_any(delimiters.clone()).await?;
+                    result.map(|(value, delimiter_index)| {
+                        // matched delimiter is recorded against the path of the value so that serialization can reproduce it
+                        let value_path = info_provider.get_mapper_context().get_last_available_spec_name()
+                            .and_then(|spec_name| info_provider.get_mapper().get_value_path(&spec_name));
+                        if let Some(value_path) = value_path {
+                            insert_matched_delimiter(info_provider.get_mapper_mut(), value_path, delimiters[delimiter_index].clone());
+                        }
+                        value
+                    })
+                }
                 Separator::NBytes(size) => {
                     reader.read_bytes( ReadBytesSize::Fixed(*size)).await?
                 }
@@ -2582,14 +3663,10 @@ impl From<serde_json::Error> for ParserError {
                 }
             };
 
-            if let Some(value) = value {
-                Ok(ValueType::parse(&spec.get_meta_data().value_type, &value))
-            } else {
-                Err(ParserError::MissingValue(format!(
-                    "Unable to read value for placeholder: {:?}",
-                    spec.get_meta_data().name.to_name_string()
-                )))
-            }
+            value.ok_or_else(|| ParserError::MissingValue(format!(
+                "Unable to read value for placeholder: {:?}",
+                spec.get_meta_data().name.to_name_string()
+            )))
     }
 
     /// SpecDeserialize for DelimitedStringSpec
@@ -2603,7 +3680,8 @@ impl From<serde_json::Error> for ParserError {
         ) -> Result<Value, ParserError>      
         {
             //let mut buf = vec![];
-             let value = parse_delimited_string_spec(self, reader).await?;
+             let value = parse_delimited_string_spec(self, info_provider, reader).await?;
+             self.validate(&info_provider.get_mapper_context().get_current_spec_path(), &value)?;
              if update_info{
                 if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name() {
                     info_provider.add_info(spec_name, value.clone())?;
@@ -2625,9 +3703,13 @@ impl From<serde_json::Error> for ParserError {
         {
             //mapper_context.start_spec(self);
             let name = self.get_meta_data().get_name();            
-            let value = info_provider.get_info_by_spec_path(&mapper_context.get_current_spec_path());
+            let spec_path = mapper_context.get_current_spec_path();
+            let value = info_provider.get_info_by_spec_path(&spec_path);
+            if let Some(value) = value {
+                self.validate(&spec_path, value)?;
+            }
             write_data(name.to_name_string(), value, self.get_meta_data().is_optional(), writer).await?;
-            if let Separator::Delimiter(delimiter) = &self.until{
+            if let Some(delimiter) = self.until.resolve_delimiter(info_provider.get_mapper(), &spec_path){
                 writer.write_all(delimiter.as_bytes()).await?;
             }
 
@@ -2638,301 +3720,2516 @@ impl From<serde_json::Error> for ParserError {
         }
     }
 
-    /// Spec that knows the exact string that appears in the protocol. This could be delimiter like space
-    /// or newline or one of the known values like request method in http request line
-    pub struct ExactStringSpec{
-        pub input: String,
-        pub spec_meta_data: SpecMetaData,
+    /// Radix of number written as text
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum Radix{
+        /// ascii decimal digits e.g Content-Length in http, reply codes in SMTP
+        #[default]
+        Decimal,
+
+        /// ascii hex digits e.g chunk size in http chunked transfer encoding
+        Hex,
     }
 
-    //impl SimpleValueSpec for ExactStringSpec{}
+    impl Radix{
+        fn value(&self) -> u32 {
+            match self {
+                Radix::Decimal => 10,
+                Radix::Hex => 16,
+            }
+        }
 
-    impl StringSpec for ExactStringSpec {}
+        /// Parses the `text` into value of number type `value_type`. Returns None if the text is not a valid number
+        fn parse(&self, value_type: &ValueType, text: &str) -> Option<Value> {
+            let radix = self.value();
+            match value_type {
+                ValueType::UnSignedNumber8 => u8::from_str_radix(text, radix).ok().map(Value::UnSignedNumber8),
+                ValueType::UnSignedNumber16 => u16::from_str_radix(text, radix).ok().map(Value::UnSignedNumber16),
+                ValueType::UnSignedNumber32 => u32::from_str_radix(text, radix).ok().map(Value::UnSignedNumber32),
+                ValueType::UnSignedNumber64 => u64::from_str_radix(text, radix).ok().map(Value::UnSignedNumber64),
+                ValueType::SignedNumber8 => i8::from_str_radix(text, radix).ok().map(Value::SignedNumber8),
+                ValueType::SignedNumber16 => i16::from_str_radix(text, radix).ok().map(Value::SignedNumber16),
+                ValueType::SignedNumber32 => i32::from_str_radix(text, radix).ok().map(Value::SignedNumber32),
+                ValueType::SignedNumber64 => i64::from_str_radix(text, radix).ok().map(Value::SignedNumber64),
+                _ => None,
+            }
+        }
 
-    impl StringSpec for OneOfSpec {}
+        /// Formats the number `value` as text. Returns None if the value is not an integer
+        fn format(&self, value: &Value) -> Option<String> {
+            let number = match value {
+                Value::UnSignedNumber8(number) => *number as i128,
+                Value::UnSignedNumber16(number) => *number as i128,
+                Value::UnSignedNumber32(number) => *number as i128,
+                Value::UnSignedNumber64(number) => *number as i128,
+                Value::SignedNumber8(number) => *number as i128,
+                Value::SignedNumber16(number) => *number as i128,
+                Value::SignedNumber32(number) => *number as i128,
+                Value::SignedNumber64(number) => *number as i128,
+                _ => return None,
+            };
+            match self {
+                Radix::Decimal => Some(number.to_string()),
+                Radix::Hex if number < 0 => Some(format!("-{:x}", -number)),
+                Radix::Hex => Some(format!("{:x}", number)),
+            }
+        }
+    }
 
-    impl ExactStringSpec{
-        fn new(name: SpecName, input: String, optional: bool) -> Self {
-            ExactStringSpec {
-                input,
-                spec_meta_data: SpecMetaData::new(name, ValueType::String, optional),
+    /// Spec to represent integer written as text digits terminated by a delimiter e.g Content-Length in http,
+    /// chunk size in hex or length prefix of Redis bulk strings. `value_type` is the integer type of the parsed value
+    #[derive(Default)]
+    pub struct TextNumberSpec{
+        spec_meta_data: SpecMetaData,
+        until: Separator,
+        validator: Option<Box<dyn ValueValidator>>,
+        radix: Radix,
+    }
+
+    impl TextNumberSpec{
+        pub fn new(name: SpecName, value_type: ValueType, radix: Radix, optional: bool) -> Self{
+            TextNumberSpec{
+                spec_meta_data: SpecMetaData::new(name, value_type, optional),
+                radix,
+                ..Default::default()
             }
         }
     }
 
-    impl  Spec for ExactStringSpec {
-        
-        fn get_meta_data(&self)-> &SpecMetaData{
+    impl Spec for TextNumberSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
             &self.spec_meta_data
         }
     }
 
+    impl SimpleValueSpec for TextNumberSpec{}
+
+    impl DelimitedSpec for TextNumberSpec{
+        fn set_delimiter(&mut self, delimiter: Separator)  {
+            self.until = delimiter;
+        }
+        
+        fn get_delimiter(&self) -> &Separator {
+            &self.until
+        }
+
+        fn set_validator(&mut self, validator: Box<dyn ValueValidator>) {
+            self.validator = Some(validator);
+        }
+
+        fn get_validator(&self) -> Option<&dyn ValueValidator> {
+            self.validator.as_deref()
+        }
+    }
 
-    /// Spec Deserializer for exact string spec
     #[async_trait]
-    impl SpecDeserialize for ExactStringSpec{
+    impl SpecDeserialize for TextNumberSpec
+    {
         async fn deserialize(
             &self,
             info_provider: &mut (dyn InfoProvider + Send + Sync),
             reader: &mut dyn SpecRead, update_info: bool,
-        ) -> Result<Value, ParserError>
+        ) -> Result<Value, ParserError>      
         {
-            let value = reader.read_placeholder_as_string(self.input.clone()).await?;
-            if let Some(value) = value {
-                if update_info && !self.spec_meta_data.get_name().is_delimiter() {
-
-                    if let Some(name) = info_provider.get_mapper_context().get_last_available_spec_name(){
-                       info_provider.add_info(name, ValueType::parse(&self.get_meta_data().value_type, &value))?;
-                    }
+            let text = read_delimited_spec(self, info_provider, reader).await?;
+            let text = std::str::from_utf8(&text)?;
+            let spec_path = info_provider.get_mapper_context().get_current_spec_path();
+            self.validate(&spec_path, &Value::String(text.to_owned()))?;
+            let value = self.radix.parse(&self.get_meta_data().value_type, text)
+                .ok_or_else(|| ParserError::InvalidValue {
+                    spec_path,
+                    value: text.to_owned(),
+                    message: format!("expected {:?} digits of {:?}", self.radix, self.get_meta_data().value_type),
+                })?;
+            if update_info{
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name() {
+                    info_provider.add_info(spec_name, value)?;
                 }
-                return Ok(ValueType::parse(&self.get_meta_data().value_type, &value));
-            } else {
-                Err(ParserError::MissingValue(format!(
-                    "Unable to read exact string for placeholder: {:?}",
-                    self.get_meta_data().get_name().to_name_string()
-                )))
+                return Ok(Value::None);
             }
+            Ok(value)
         }
     }
 
-    /// SpecSerializer for exact string spec
     #[async_trait]
-    impl SpecSerialize for ExactStringSpec
+    impl SpecSerialize for TextNumberSpec
     {
         async fn serialize (
             &self,
             info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
             writer: &mut dyn SpecWrite,
         ) -> Result<(), ParserError>
-        {   
+        {
+            let spec_path = mapper_context.get_current_spec_path();
+            match info_provider.get_info_by_spec_path(&spec_path) {
+                Some(value) => {
+                    // numbers available as text e.g headers populated by handlers are normalized
+                    let number = match value {
+                        Value::String(text) => self.radix.parse(&self.get_meta_data().value_type, text),
+                        _ => Some(value.clone()),
+                    };
+                    let text = number.as_ref().and_then(|number| self.radix.format(number))
+                        .ok_or_else(|| ParserError::InvalidValue {
+                            spec_path: spec_path.clone(),
+                            value: format!("{:?}", value),
+                            message: format!("expected {:?} number of {:?}", self.radix, self.get_meta_data().value_type),
+                        })?;
+                    writer.write_string(text).await?;
+                },
+                None if self.get_meta_data().is_optional() => {},
+                None => return Err(ParserError::MissingData(self.get_meta_data().get_name().to_name_string())),
+            }
+            if let Some(delimiter) = self.until.resolve_delimiter(info_provider.get_mapper(), &spec_path){
+                writer.write_all(delimiter.as_bytes()).await?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Text format of timestamp
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    pub enum TimestampFormat{
+        /// IMF-fixdate of RFC 7231 e.g `Sun, 06 Nov 1994 08:49:37 GMT` used by http Date and Last-Modified headers
+        #[default]
+        ImfFixdate,
+
+        /// RFC 3339 e.g `1994-11-06T08:49:37Z`
+        Rfc3339,
+
+        /// chrono format string e.g `%Y%m%d%H%M%S`. Timestamps without offset are treated as UTC
+        Custom(String),
+    }
+
+    const IMF_FIXDATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+    impl TimestampFormat{
+        /// Parses the `text` into timestamp. Returns None if the text is not in this format
+        pub fn parse(&self, text: &str) -> Option<DateTime<Utc>> {
+            match self {
+                TimestampFormat::ImfFixdate => NaiveDateTime::parse_from_str(text, IMF_FIXDATE_FORMAT).ok().map(|timestamp| timestamp.and_utc()),
+                TimestampFormat::Rfc3339 => DateTime::parse_from_rfc3339(text).ok().map(|timestamp| timestamp.with_timezone(&Utc)),
+                TimestampFormat::Custom(format) => DateTime::parse_from_str(text, format).ok().map(|timestamp| timestamp.with_timezone(&Utc))
+                    .or_else(|| NaiveDateTime::parse_from_str(text, format).ok().map(|timestamp| timestamp.and_utc())),
+            }
+        }
+
+        /// Formats the `timestamp` as text
+        pub fn format(&self, timestamp: &DateTime<Utc>) -> String {
+            match self {
+                TimestampFormat::ImfFixdate => timestamp.format(IMF_FIXDATE_FORMAT).to_string(),
+                TimestampFormat::Rfc3339 => timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+                TimestampFormat::Custom(format) => timestamp.format(format).to_string(),
+            }
+        }
+    }
+
+    /// Spec to represent timestamp written as text terminated by a delimiter e.g http Date header
+    #[derive(Default)]
+    pub struct TextTimestampSpec{
+        spec_meta_data: SpecMetaData,
+        until: Separator,
+        validator: Option<Box<dyn ValueValidator>>,
+        format: TimestampFormat,
+    }
+
+    impl TextTimestampSpec{
+        pub fn new(name: SpecName, format: TimestampFormat, optional: bool) -> Self{
+            TextTimestampSpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::Timestamp, optional),
+                format,
+                ..Default::default()
+            }
+        }
+    }
+
+    impl Spec for TextTimestampSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    impl SimpleValueSpec for TextTimestampSpec{}
+
+    impl DelimitedSpec for TextTimestampSpec{
+        fn set_delimiter(&mut self, delimiter: Separator)  {
+            self.until = delimiter;
+        }
+        
+        fn get_delimiter(&self) -> &Separator {
+            &self.until
+        }
+
+        fn set_validator(&mut self, validator: Box<dyn ValueValidator>) {
+            self.validator = Some(validator);
+        }
+
+        fn get_validator(&self) -> Option<&dyn ValueValidator> {
+            self.validator.as_deref()
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for TextTimestampSpec
+    {
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>      
+        {
+            let text = read_delimited_spec(self, info_provider, reader).await?;
+            let text = std::str::from_utf8(&text)?;
+            let spec_path = info_provider.get_mapper_context().get_current_spec_path();
+            self.validate(&spec_path, &Value::String(text.to_owned()))?;
+            let value = self.format.parse(text).map(Value::Timestamp)
+                .ok_or_else(|| ParserError::InvalidValue {
+                    spec_path,
+                    value: text.to_owned(),
+                    message: format!("expected timestamp in {:?} format", self.format),
+                })?;
+            if update_info{
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name() {
+                    info_provider.add_info(spec_name, value)?;
+                }
+                return Ok(Value::None);
+            }
+            Ok(value)
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for TextTimestampSpec
+    {
+        async fn serialize (
+            &self,
+            info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,
+        ) -> Result<(), ParserError>
+        {
+            let spec_path = mapper_context.get_current_spec_path();
+            match info_provider.get_info_by_spec_path(&spec_path) {
+                Some(value) => {
+                    let timestamp = value.get_timestamp_value()
+                        .ok_or_else(|| ParserError::InvalidValue {
+                            spec_path: spec_path.clone(),
+                            value: format!("{:?}", value),
+                            message: "expected timestamp value".to_string(),
+                        })?;
+                    writer.write_string(self.format.format(&timestamp)).await?;
+                },
+                None if self.get_meta_data().is_optional() => {},
+                None => return Err(ParserError::MissingData(self.get_meta_data().get_name().to_name_string())),
+            }
+            if let Some(delimiter) = self.until.resolve_delimiter(info_provider.get_mapper(), &spec_path){
+                writer.write_all(delimiter.as_bytes()).await?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Unit of binary timestamp counted from unix epoch
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum EpochUnit{
+        /// seconds e.g ntp style and file formats
+        #[default]
+        Seconds,
+
+        /// milliseconds e.g java and javascript timestamps
+        Millis,
+    }
+
+    /// Spec to represent timestamp encoded as binary number of `EpochUnit`s from unix epoch.
+    /// `word_type` is ValueType::UnSignedNumber32 or ValueType::UnSignedNumber64
+    pub struct EpochTimestampSpec{
+        spec_meta_data: SpecMetaData,
+        unit: EpochUnit,
+        word_type: ValueType,
+        endianness: Endianness,
+    }
+
+    impl EpochTimestampSpec{
+        pub fn new(name: SpecName, unit: EpochUnit, word_type: ValueType, endianness: Endianness, optional: bool) -> Self{
+            EpochTimestampSpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::Timestamp, optional),
+                unit,
+                word_type,
+                endianness,
+            }
+        }
+
+        fn invalid_value(&self, spec_path: String, value: String, message: String) -> ParserError{
+            ParserError::InvalidValue { spec_path, value, message }
+        }
+
+        /// Returns the size of the word in bytes
+        fn word_size(&self, spec_path: &str) -> Result<u32, ParserError>{
+            match self.word_type {
+                ValueType::UnSignedNumber32 => Ok(4),
+                ValueType::UnSignedNumber64 => Ok(8),
+                ref word_type => Err(self.invalid_value(spec_path.to_owned(), format!("{:?}", word_type), "epoch timestamp is supported only for u32 and u64".to_string())),
+            }
+        }
+    }
+
+    impl Spec for EpochTimestampSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    impl SimpleValueSpec for EpochTimestampSpec{}
+
+    #[async_trait]
+    impl SpecDeserialize for EpochTimestampSpec{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let spec_path = info_provider.get_mapper_context().get_current_spec_path();
+            let size = self.word_size(&spec_path)?;
+            let data = reader.read_bytes(ReadBytesSize::Fixed(size)).await?
+                .ok_or_else(|| ParserError::MissingValue(format!(
+                    "Unable to read {} bytes for placeholder: {:?}",
+                    size, self.get_meta_data().get_name().to_name_string()
+                )))?;
+            let count = match ValueType::parse_with_endianness(&self.word_type, &data, self.endianness) {
+                Value::UnSignedNumber32(count) => count as i64,
+                Value::UnSignedNumber64(count) => i64::try_from(count).unwrap_or(i64::MAX),
+                value => return Err(self.invalid_value(spec_path, format!("{:?}", value), "epoch timestamp is not an unsigned number".to_string())),
+            };
+            let timestamp = match self.unit {
+                EpochUnit::Seconds => DateTime::from_timestamp(count, 0),
+                EpochUnit::Millis => DateTime::from_timestamp_millis(count),
+            };
+            let value = timestamp.map(Value::Timestamp)
+                .ok_or_else(|| self.invalid_value(spec_path, count.to_string(), format!("epoch {:?} is out of range", self.unit)))?;
+            if update_info{
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name() {
+                    info_provider.add_info(spec_name, value)?;
+                }
+                return Ok(Value::None);
+            }
+            Ok(value)
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for EpochTimestampSpec{
+        async fn serialize (
+            &self,
+            info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,
+        ) -> Result<(), ParserError>
+        {
+            let spec_path = mapper_context.get_current_spec_path();
+            self.word_size(&spec_path)?;
+            let Some(value) = info_provider.get_info_by_spec_path(&spec_path) else {
+                if self.get_meta_data().is_optional() {
+                    return Ok(());
+                }
+                return Err(ParserError::MissingData(self.get_meta_data().get_name().to_name_string()));
+            };
+            let timestamp = value.get_timestamp_value()
+                .ok_or_else(|| self.invalid_value(spec_path.clone(), format!("{:?}", value), "expected timestamp value".to_string()))?;
+            let count = match self.unit {
+                EpochUnit::Seconds => timestamp.timestamp(),
+                EpochUnit::Millis => timestamp.timestamp_millis(),
+            };
+            let word = match self.word_type {
+                ValueType::UnSignedNumber32 => u32::try_from(count).ok().map(Value::UnSignedNumber32),
+                _ => u64::try_from(count).ok().map(Value::UnSignedNumber64),
+            }.ok_or_else(|| self.invalid_value(spec_path, count.to_string(), format!("epoch {:?} does not fit into {:?}", self.unit, self.word_type)))?;
+            writer.write_data_bytes(&word.to_bytes(self.endianness)?).await
+        }
+    }
+
+    /// Kind of network address. Binary addresses are in network byte order e.g DNS A/AAAA records, SOCKS5 requests.
+    /// `Ip` and `Socket` accept both IPv4 and IPv6 and are supported only in text form
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AddressKind{
+        /// 4 bytes or dotted decimal text
+        Ipv4,
+
+        /// 16 bytes or colon separated hex text
+        Ipv6,
+
+        /// IPv4 or IPv6 text
+        Ip,
+
+        /// 4 bytes of address followed by 2 bytes of port or `address:port` text
+        SocketV4,
+
+        /// 16 bytes of address followed by 2 bytes of port or `[address]:port` text
+        SocketV6,
+
+        /// IPv4 or IPv6 socket address text
+        Socket,
+
+        /// 6 bytes of MAC address or colon separated hex text e.g `00:1a:2b:3c:4d:5e`
+        Mac,
+    }
+
+    impl AddressKind{
+        fn value_type(&self) -> ValueType {
+            match self {
+                AddressKind::Ipv4 | AddressKind::Ipv6 | AddressKind::Ip => ValueType::IpAddr,
+                AddressKind::SocketV4 | AddressKind::SocketV6 | AddressKind::Socket => ValueType::SocketAddr,
+                AddressKind::Mac => ValueType::MacAddr,
+            }
+        }
+
+        /// Returns size of binary address. Returns None for kinds that are supported only in text form
+        fn size(&self) -> Option<u32> {
+            match self {
+                AddressKind::Ipv4 => Some(4),
+                AddressKind::Ipv6 => Some(16),
+                AddressKind::SocketV4 => Some(6),
+                AddressKind::SocketV6 => Some(18),
+                AddressKind::Mac => Some(6),
+                AddressKind::Ip | AddressKind::Socket => None,
+            }
+        }
+
+        /// Returns the `value` if it is an address of this kind. Address text is parsed
+        fn address_of(&self, value: &Value) -> Option<Value> {
+            let value = match value {
+                Value::String(text) => self.parse(text)?,
+                value => value.clone(),
+            };
+            let matches = match (self, &value) {
+                (AddressKind::Ipv4, Value::IpAddr(address)) => address.is_ipv4(),
+                (AddressKind::Ipv6, Value::IpAddr(address)) => address.is_ipv6(),
+                (AddressKind::SocketV4, Value::SocketAddr(address)) => address.is_ipv4(),
+                (AddressKind::SocketV6, Value::SocketAddr(address)) => address.is_ipv6(),
+                (AddressKind::Ip, Value::IpAddr(_)) | (AddressKind::Socket, Value::SocketAddr(_)) | (AddressKind::Mac, Value::MacAddr(_)) => true,
+                _ => false,
+            };
+            matches.then_some(value)
+        }
+
+        /// Decodes binary address
+        fn decode(&self, data: &[u8]) -> Option<Value> {
+            let value = match self {
+                AddressKind::Ipv4 => Value::IpAddr(IpAddr::from(<[u8; 4]>::try_from(data).ok()?)),
+                AddressKind::Ipv6 => Value::IpAddr(IpAddr::from(<[u8; 16]>::try_from(data).ok()?)),
+                AddressKind::SocketV4 | AddressKind::SocketV6 => {
+                    let (address, port) = data.split_at(data.len().checked_sub(2)?);
+                    let address = match self {
+                        AddressKind::SocketV4 => AddressKind::Ipv4.decode(address)?,
+                        _ => AddressKind::Ipv6.decode(address)?,
+                    };
+                    let Value::IpAddr(address) = address else { return None };
+                    Value::SocketAddr(SocketAddr::new(address, u16::from_be_bytes([port[0], port[1]])))
+                }
+                AddressKind::Mac => Value::MacAddr(<[u8; 6]>::try_from(data).ok()?),
+                AddressKind::Ip | AddressKind::Socket => return None,
+            };
+            Some(value)
+        }
+
+        /// Encodes address `value` of this kind into bytes
+        fn encode(&self, value: &Value) -> Option<Vec<u8>> {
+            self.size()?;
+            match self.address_of(value)? {
+                value @ (Value::IpAddr(_) | Value::SocketAddr(_) | Value::MacAddr(_)) => value.to_bytes(Endianness::Big).ok(),
+                _ => None,
+            }
+        }
+
+        /// Parses address text
+        fn parse(&self, text: &str) -> Option<Value> {
+            let value = match self {
+                AddressKind::Ipv4 => Value::IpAddr(IpAddr::V4(text.parse().ok()?)),
+                AddressKind::Ipv6 => Value::IpAddr(IpAddr::V6(text.parse().ok()?)),
+                AddressKind::Ip => Value::IpAddr(text.parse().ok()?),
+                AddressKind::SocketV4 => Value::SocketAddr(SocketAddr::V4(text.parse().ok()?)),
+                AddressKind::SocketV6 => Value::SocketAddr(SocketAddr::V6(text.parse().ok()?)),
+                AddressKind::Socket => Value::SocketAddr(text.parse().ok()?),
+                AddressKind::Mac => {
+                    let octets = text.split([':', '-'])
+                        .map(|octet| (octet.len() == 2).then(|| u8::from_str_radix(octet, 16).ok()).flatten())
+                        .collect::<Option<Vec<u8>>>()?;
+                    Value::MacAddr(octets.try_into().ok()?)
+                }
+            };
+            Some(value)
+        }
+    }
+
+    /// Spec to represent binary network address e.g IPv4 address of DNS A record, MAC address in DHCP
+    pub struct AddressSpec{
+        spec_meta_data: SpecMetaData,
+        kind: AddressKind,
+    }
+
+    impl AddressSpec{
+        pub fn new(name: SpecName, kind: AddressKind, optional: bool) -> Self{
+            AddressSpec{
+                spec_meta_data: SpecMetaData::new(name, kind.value_type(), optional),
+                kind,
+            }
+        }
+
+        fn unsupported_kind(&self, spec_path: String) -> ParserError{
+            ParserError::InvalidValue {
+                spec_path,
+                value: format!("{:?}", self.kind),
+                message: "address kind is supported only in text form".to_string(),
+            }
+        }
+    }
+
+    impl Spec for AddressSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    impl SimpleValueSpec for AddressSpec{}
+
+    #[async_trait]
+    impl SpecDeserialize for AddressSpec{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let Some(size) = self.kind.size() else {
+                return Err(self.unsupported_kind(info_provider.get_mapper_context().get_current_spec_path()));
+            };
+            let data = reader.read_bytes(ReadBytesSize::Fixed(size)).await?
+                .ok_or_else(|| ParserError::MissingValue(format!(
+                    "Unable to read {} bytes for placeholder: {:?}",
+                    size, self.get_meta_data().get_name().to_name_string()
+                )))?;
+            let value = self.kind.decode(&data).ok_or_else(|| self.unsupported_kind(info_provider.get_mapper_context().get_current_spec_path()))?;
+            if update_info{
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name() {
+                    info_provider.add_info(spec_name, value)?;
+                }
+                return Ok(Value::None);
+            }
+            Ok(value)
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for AddressSpec{
+        async fn serialize (
+            &self,
+            info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,
+        ) -> Result<(), ParserError>
+        {
+            let spec_path = mapper_context.get_current_spec_path();
+            if self.kind.size().is_none() {
+                return Err(self.unsupported_kind(spec_path));
+            }
+            match info_provider.get_info_by_spec_path(&spec_path) {
+                Some(value) => {
+                    let data = self.kind.encode(value).ok_or_else(|| ParserError::InvalidValue {
+                        spec_path,
+                        value: format!("{:?}", value),
+                        message: format!("expected {:?} address", self.kind),
+                    })?;
+                    writer.write_data_bytes(&data).await
+                },
+                None if self.get_meta_data().is_optional() => Ok(()),
+                None => Err(ParserError::MissingData(self.get_meta_data().get_name().to_name_string())),
+            }
+        }
+    }
+
+    /// Spec to represent network address written as text terminated by a delimiter e.g client address in PROXY protocol header
+    pub struct TextAddressSpec{
+        spec_meta_data: SpecMetaData,
+        until: Separator,
+        validator: Option<Box<dyn ValueValidator>>,
+        kind: AddressKind,
+    }
+
+    impl Default for TextAddressSpec{
+        fn default() -> Self {
+            TextAddressSpec::new(SpecName::NoName, AddressKind::Ip, false)
+        }
+    }
+
+    impl TextAddressSpec{
+        pub fn new(name: SpecName, kind: AddressKind, optional: bool) -> Self{
+            TextAddressSpec{
+                spec_meta_data: SpecMetaData::new(name, kind.value_type(), optional),
+                until: Separator::default(),
+                validator: None,
+                kind,
+            }
+        }
+    }
+
+    impl Spec for TextAddressSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    impl SimpleValueSpec for TextAddressSpec{}
+
+    impl DelimitedSpec for TextAddressSpec{
+        fn set_delimiter(&mut self, delimiter: Separator)  {
+            self.until = delimiter;
+        }
+        
+        fn get_delimiter(&self) -> &Separator {
+            &self.until
+        }
+
+        fn set_validator(&mut self, validator: Box<dyn ValueValidator>) {
+            self.validator = Some(validator);
+        }
+
+        fn get_validator(&self) -> Option<&dyn ValueValidator> {
+            self.validator.as_deref()
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for TextAddressSpec
+    {
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>      
+        {
+            let text = read_delimited_spec(self, info_provider, reader).await?;
+            let text = std::str::from_utf8(&text)?;
+            let spec_path = info_provider.get_mapper_context().get_current_spec_path();
+            self.validate(&spec_path, &Value::String(text.to_owned()))?;
+            let value = self.kind.parse(text)
+                .ok_or_else(|| ParserError::InvalidValue {
+                    spec_path,
+                    value: text.to_owned(),
+                    message: format!("expected {:?} address", self.kind),
+                })?;
+            if update_info{
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name() {
+                    info_provider.add_info(spec_name, value)?;
+                }
+                return Ok(Value::None);
+            }
+            Ok(value)
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for TextAddressSpec
+    {
+        async fn serialize (
+            &self,
+            info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,
+        ) -> Result<(), ParserError>
+        {
+            let spec_path = mapper_context.get_current_spec_path();
+            match info_provider.get_info_by_spec_path(&spec_path) {
+                Some(value) => {
+                    let text = self.kind.address_of(value).and_then(|address| address.get_string_value())
+                        .ok_or_else(|| ParserError::InvalidValue {
+                            spec_path: spec_path.clone(),
+                            value: format!("{:?}", value),
+                            message: format!("expected {:?} address", self.kind),
+                        })?;
+                    writer.write_string(text).await?;
+                },
+                None if self.get_meta_data().is_optional() => {},
+                None => return Err(ParserError::MissingData(self.get_meta_data().get_name().to_name_string())),
+            }
+            if let Some(delimiter) = self.until.resolve_delimiter(info_provider.get_mapper(), &spec_path){
+                writer.write_all(delimiter.as_bytes()).await?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Spec to represent string terminated by NUL byte e.g strings in postgres wire protocol and TFTP
+    pub struct CStringSpec{
+        spec_meta_data: SpecMetaData,
+    }
+
+    impl CStringSpec{
+        pub fn new(name: SpecName, optional: bool) -> Self{
+            CStringSpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::String, optional),
+            }
+        }
+    }
+
+    impl Spec for CStringSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    impl StringSpec for CStringSpec{}
+
+    #[async_trait]
+    impl SpecDeserialize for CStringSpec{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let value = reader.read_placeholder_until("\0".to_owned()).await?
+                .ok_or_else(|| ParserError::MissingValue(format!(
+                    "Unable to read nul terminated string for placeholder: {:?}",
+                    self.get_meta_data().get_name().to_name_string()
+                )))?;
+            let value = Value::String(std::str::from_utf8(&value)?.to_owned());
+            if update_info{
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name() {
+                    info_provider.add_info(spec_name, value)?;
+                }
+                return Ok(Value::None);
+            }
+            Ok(value)
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for CStringSpec{
+        async fn serialize (
+            &self,
+            info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,
+        ) -> Result<(), ParserError>
+        {
+            let spec_path = mapper_context.get_current_spec_path();
+            let data = match info_provider.get_info_by_spec_path(&spec_path) {
+                Some(Value::String(value)) => value.as_bytes(),
+                Some(Value::U8Vec(value)) => value.as_slice(),
+                Some(value) => return Err(ParserError::InvalidValue {
+                    spec_path,
+                    value: format!("{:?}", value),
+                    message: "nul terminated string must be string or bytes".to_string(),
+                }),
+                None if self.get_meta_data().is_optional() => return Ok(()),
+                None => return Err(ParserError::MissingData(self.get_meta_data().get_name().to_name_string())),
+            };
+            if data.contains(&0) {
+                return Err(ParserError::InvalidValue {
+                    spec_path,
+                    value: String::from_utf8_lossy(data).to_string(),
+                    message: "nul terminated string cannot contain nul byte".to_string(),
+                });
+            }
+            writer.write_data_bytes(data).await?;
+            writer.write_data_u8(0).await
+        }
+    }
+
+    /// Spec to represent string enclosed in `quote` where quote and escape bytes inside the string are preceded by `escape`
+    /// e.g HTTP quoted-string and IMAP quoted. Unescaped string is stored and the string is escaped again on write
+    pub struct QuotedStringSpec{
+        spec_meta_data: SpecMetaData,
+        quote: u8,
+        escape: u8,
+    }
+
+    impl QuotedStringSpec{
+        pub fn new(name: SpecName, quote: u8, escape: u8, optional: bool) -> Self{
+            QuotedStringSpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::String, optional),
+                quote,
+                escape,
+            }
+        }
+
+        async fn read_byte(&self, reader: &mut dyn SpecRead) -> Result<u8, ParserError> {
+            reader.read_bytes(ReadBytesSize::Fixed(1)).await?
+                .and_then(|bytes| bytes.first().copied())
+                .ok_or_else(|| ParserError::MissingValue(format!(
+                    "Unable to read quoted string for placeholder: {:?}",
+                    self.get_meta_data().get_name().to_name_string()
+                )))
+        }
+    }
+
+    impl Spec for QuotedStringSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    impl StringSpec for QuotedStringSpec{}
+
+    #[async_trait]
+    impl SpecDeserialize for QuotedStringSpec{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let opening = self.read_byte(reader).await?;
+            if opening != self.quote {
+                return Err(ParserError::InvalidValue {
+                    spec_path: info_provider.get_mapper_context().get_current_spec_path(),
+                    value: (opening as char).to_string(),
+                    message: format!("expected opening quote {:?}", self.quote as char),
+                });
+            }
+            let unescaped = reader.read_quoted(self.quote, self.escape).await?
+                .ok_or_else(|| ParserError::MissingValue(format!(
+                    "Closing quote not found for placeholder: {:?}",
+                    self.get_meta_data().get_name().to_name_string()
+                )))?;
+            let value = Value::String(String::from_utf8(unescaped).map_err(|e| e.utf8_error())?);
+            if update_info{
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name() {
+                    info_provider.add_info(spec_name, value)?;
+                }
+                return Ok(Value::None);
+            }
+            Ok(value)
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for QuotedStringSpec{
+        async fn serialize (
+            &self,
+            info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,
+        ) -> Result<(), ParserError>
+        {
+            let spec_path = mapper_context.get_current_spec_path();
+            match info_provider.get_info_by_spec_path(&spec_path) {
+                Some(Value::String(value)) => {
+                    let mut escaped = vec![self.quote];
+                    for byte in value.bytes() {
+                        if byte == self.quote || byte == self.escape {
+                            escaped.push(self.escape);
+                        }
+                        escaped.push(byte);
+                    }
+                    escaped.push(self.quote);
+                    writer.write_data_bytes(&escaped).await
+                },
+                Some(value) => Err(ParserError::InvalidValue {
+                    spec_path,
+                    value: format!("{:?}", value),
+                    message: "quoted string expects string value".to_string(),
+                }),
+                None if self.get_meta_data().is_optional() => Ok(()),
+                None => Err(ParserError::MissingData(self.get_meta_data().get_name().to_name_string())),
+            }
+        }
+    }
+
+    /// Side of the fixed width field where data is placed. Padding is added on the other side
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum Alignment{
+        /// Data is followed by padding e.g space padded names
+        #[default]
+        Left,
+
+        /// Padding is followed by data e.g zero padded numbers
+        Right,
+    }
+
+    /// Spec to represent string of fixed width padded with pad byte e.g fields of legacy mainframe records.
+    /// Padding is trimmed on read and added on write
+    pub struct FixedWidthStringSpec{
+        spec_meta_data: SpecMetaData,
+        width: u32,
+        pad_byte: u8,
+        alignment: Alignment,
+    }
+
+    impl FixedWidthStringSpec{
+        pub fn new(name: SpecName, width: u32, pad_byte: u8, alignment: Alignment, optional: bool) -> Self{
+            FixedWidthStringSpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::String, optional),
+                width,
+                pad_byte,
+                alignment,
+            }
+        }
+
+        /// Removes padding from the data read. Field made only of the pad byte is read as empty string for
+        /// both alignments, e.g `00000` padded with `0` is read as ""
+        fn trim<'a>(&self, data: &'a [u8]) -> &'a [u8]{
+            match self.alignment {
+                Alignment::Left => {
+                    let end = data.iter().rposition(|byte| *byte != self.pad_byte).map_or(0, |index| index + 1);
+                    &data[..end]
+                },
+                Alignment::Right => {
+                    let start = data.iter().position(|byte| *byte != self.pad_byte).unwrap_or(data.len());
+                    &data[start..]
+                },
+            }
+        }
+
+        /// Adds padding to the data to be written
+        fn pad(&self, data: &[u8]) -> Vec<u8>{
+            let padding = vec![self.pad_byte; self.width as usize - data.len()];
+            match self.alignment {
+                Alignment::Left => [data, &padding].concat(),
+                Alignment::Right => [&padding, data].concat(),
+            }
+        }
+    }
+
+    impl Spec for FixedWidthStringSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    impl StringSpec for FixedWidthStringSpec{}
+
+    #[async_trait]
+    impl SpecDeserialize for FixedWidthStringSpec{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let data = reader.read_bytes(ReadBytesSize::Fixed(self.width)).await?
+                .ok_or_else(|| ParserError::MissingValue(format!(
+                    "Unable to read {} bytes for placeholder: {:?}",
+                    self.width, self.get_meta_data().get_name().to_name_string()
+                )))?;
+            let value = Value::String(std::str::from_utf8(self.trim(&data))?.to_owned());
+            if update_info{
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name() {
+                    info_provider.add_info(spec_name, value)?;
+                }
+                return Ok(Value::None);
+            }
+            Ok(value)
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for FixedWidthStringSpec{
+        async fn serialize (
+            &self,
+            info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,
+        ) -> Result<(), ParserError>
+        {
+            let spec_path = mapper_context.get_current_spec_path();
+            match info_provider.get_info_by_spec_path(&spec_path) {
+                Some(Value::String(value)) if value.len() > self.width as usize => Err(ParserError::InvalidValue {
+                    spec_path,
+                    value: value.clone(),
+                    message: format!("string is longer than the width {}", self.width),
+                }),
+                Some(Value::String(value)) => writer.write_data_bytes(&self.pad(value.as_bytes())).await,
+                Some(value) => Err(ParserError::InvalidValue {
+                    spec_path,
+                    value: format!("{:?}", value),
+                    message: "fixed width string spec expects string value".to_string(),
+                }),
+                None if self.get_meta_data().is_optional() => Ok(()),
+                None => Err(ParserError::MissingData(self.get_meta_data().get_name().to_name_string())),
+            }
+        }
+    }
+
+    /// Spec that knows the exact string that appears in the protocol. This could be delimiter like space
+    /// or newline or one of the known values like request method in http request line
+    pub struct ExactStringSpec{
+        pub input: String,
+        pub spec_meta_data: SpecMetaData,
+        pub ignore_case: bool,
+    }
+
+    //impl SimpleValueSpec for ExactStringSpec{}
+
+    impl StringSpec for ExactStringSpec {}
+
+    impl StringSpec for OneOfSpec {}
+
+    impl ExactStringSpec{
+        fn new(name: SpecName, input: String, optional: bool) -> Self {
+            ExactStringSpec {
+                input,
+                spec_meta_data: SpecMetaData::new(name, ValueType::String, optional),
+                ignore_case: false,
+            }
+        }
+
+        /// Creates exact string spec that matches the input ignoring ascii case. `input` is stored as the value when matched
+        pub fn new_ignore_case(name: SpecName, input: String, optional: bool) -> Self {
+            ExactStringSpec {
+                ignore_case: true,
+                ..ExactStringSpec::new(name, input, optional)
+            }
+        }
+    }
+
+    impl  Spec for ExactStringSpec {
+        
+        fn get_meta_data(&self)-> &SpecMetaData{
+            &self.spec_meta_data
+        }
+    }
+
+
+    /// Spec Deserializer for exact string spec
+    #[async_trait]
+    impl SpecDeserialize for ExactStringSpec{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let value = if self.ignore_case {
+                // matched value is normalized to the input of the spec
+                reader.read_placeholder_as_string_ignore_case(self.input.clone()).await?
+                    .map(|_| self.input.as_bytes().to_vec())
+            } else {
+                reader.read_placeholder_as_string(self.input.clone()).await?
+            };
+            if let Some(value) = value {
+                if update_info && !self.spec_meta_data.get_name().is_delimiter() {
+
+                    if let Some(name) = info_provider.get_mapper_context().get_last_available_spec_name(){
+                       info_provider.add_info(name, ValueType::parse(&self.get_meta_data().value_type, &value))?;
+                    }
+                }
+                return Ok(ValueType::parse(&self.get_meta_data().value_type, &value));
+            } else {
+                Err(ParserError::MissingValue(format!(
+                    "Unable to read exact string for placeholder: {:?}",
+                    self.get_meta_data().get_name().to_name_string()
+                )))
+            }
+        }
+    }
+
+    /// SpecSerializer for exact string spec
+    #[async_trait]
+    impl SpecSerialize for ExactStringSpec
+    {
+        async fn serialize (
+            &self,
+            info_provider: & ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,
+        ) -> Result<(), ParserError>
+        {   
+            //mapper_context.start_spec(self);
+
+            let name = self.get_meta_data().get_name().to_name_string();
+            if let SpecName::Delimiter = self.get_meta_data().get_name() {
+                write_data(name, Some(&Value::String(self.input.to_owned())), 
+                    self.get_meta_data().is_optional(), 
+                    writer).await?;
+            }else{
+                let value = info_provider.get_info_by_spec_path(&mapper_context.get_current_spec_path());
+                write_data(name, value, self.get_meta_data().is_optional(), writer).await?;
+            }
+            
+            
+            
+
+            Ok(())
+        }
+    }
+
+
+    ///Key value spec. It is a composite spec(it contains other Spec) containing Key and Value Spec
+    pub(crate) struct KeyValueSpec{
+        pub spec_metadata: SpecMetaData,
+        pub key: Key,
+        pub value: ValueSpec,
+    }
+    
+    impl KeyValueSpec{
+        pub fn new(key: Key, value: ValueSpec, spec_metadata: SpecMetaData) -> Self {
+            KeyValueSpec {
+                spec_metadata,
+                key,
+                value,
+            }
+        }
+
+        /// Whether the value contains other specs e.g entries repeated as the value of the key
+        pub(crate) fn has_composite_value(&self) -> bool {
+            matches!(self.value.0.get_meta_data().get_value_type(), ValueType::CompositeList | ValueType::CompositeMap)
+        }
+    }
+
+    /// Converts deserialized key to the name under which its value is stored. Bytes keys are decoded as utf8
+    fn key_to_string(key: &Value) -> Result<String, ParserError>{
+        match key {
+            Value::U8Vec(bytes) => String::from_utf8(bytes.clone()).map_err(|e| ParserError::Utf8Error(e.utf8_error())),
+            _ => key.get_string_value_unchecked(),
+        }
+    }
+
+    pub(crate) fn extract_name_and_spec_path<F, S> (
+        spec_path_finder: F,
+        mapper: &mut dyn Mapper, spec: &S, inner_spec: &dyn ProtocolSpec ) -> Result<(Option<String>, Option<String>), ParserError>
+        where F: Fn(&dyn Mapper) -> String,
+        S: ProtocolSpec,
+        {
+        mapper.get_mapper_context_mut().start_spec_type(spec.to_spec_type());
+        mapper.get_mapper_context_mut().start_spec_type(inner_spec.to_spec_type());
+        let spec_name = mapper.get_mapper_context().get_last_available_spec_name();
+        let spec_path = Some(
+                spec_path_finder(mapper)                
+            );
+        mapper.get_mapper_context_mut().end_spec(inner_spec)?;
+        mapper.get_mapper_context_mut().end_spec(spec)?;
+        Ok((spec_name, spec_path))
+    }
+
+
+    impl Spec for KeyValueSpec{
+        fn get_meta_data(&self) -> &SpecMetaData{
+            &self.spec_metadata
+        }
+    }
+
+
+
+    #[async_trait]
+    impl SpecSerialize for KeyValueSpec {
+        async fn serialize(
+            &self,
+            info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,            
+        ) -> Result<(), ParserError>
+        {
+            serialize(&self.key, info_provider, writer, mapper_context).await?;
+            serialize(&self.value, info_provider, writer, mapper_context).await?;            
+            Ok(())
+        }
+
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            prepare(&self.key, info_provider, mapper_context)?;
+            prepare(&self.value, info_provider, mapper_context)
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for KeyValueSpec{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,            
+    ) -> Result<Value, ParserError>
+        {
+            let path_finder =  |mapper:  &dyn Mapper| {mapper.get_mapper_context().get_current_spec_path()};
+            let ( key_spec_name,  key_spec_path,) = extract_name_and_spec_path(path_finder, info_provider.get_mapper_mut(), &self.key, &self.key.0)?;
+            let key_name = undoable_deserialize(&self.key, info_provider, reader, false).await?;            
+            let ( value_spec_name,  value_spec_path,) = extract_name_and_spec_path(path_finder,info_provider.get_mapper_mut(), &self.value, &self.value.0)?;           
+            // composite values e.g repeated entries store their constituents at their own spec paths
+            let value = undoable_deserialize(&self.value, info_provider, reader, update_info && self.has_composite_value()).await?;
+            if !update_info {
+                let key = key_to_string(&key_name)?;
+                return Ok(Value::Map(HashMap::from([(key, value)])));
+            }
+            match (key_spec_name, value_spec_name){
+                (Some(key_spec_name), Some(value_spec_name)) => {
+                    info_provider.get_mapper_mut().add_to_key_value_list(key_to_string(&key_name)?,
+                        value, key_spec_name, value_spec_name)?;
+                },
+                // pair is looked up using names of the key and the value
+                _ => return Err(ParserError::InvalidValue {
+                    spec_path: key_spec_path.or(value_spec_path).unwrap_or_default(),
+                    value: format!("{:?}", key_name),
+                    message: "key and value of key-value spec must be named".to_string(),
+                }),
+            }
+            return Ok(Value::None);            
+        }
+    }
+
+    /// Spec to represent constant sequence of bytes e.g magic number `\x89PNG` or `0xCAFEBABE` of binary formats.
+    /// The bytes are verified during deserialization and always written during serialization
+    pub struct ExactBytesSpec{
+        spec_meta_data: SpecMetaData,
+        bytes: Vec<u8>,
+    }
+
+    impl ExactBytesSpec{
+        pub fn new(name: SpecName, bytes: Vec<u8>, optional: bool) -> Self{
+            ExactBytesSpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::U8Vec, optional),
+                bytes,
+            }
+        }
+    }
+
+    impl Spec for ExactBytesSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for ExactBytesSpec{
+        async fn deserialize(
+            &self,
+            _info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let Some(bytes) = reader.read_bytes(ReadBytesSize::Fixed(self.bytes.len() as u32)).await? else {
+                return Err(ParserError::MissingValue(format!(
+                    "Unable to read {} bytes for placeholder: {:?}",
+                    self.bytes.len(), self.get_meta_data().get_name().to_name_string()
+                )));
+            };
+            if let Some(offset) = bytes.iter().zip(&self.bytes).position(|(found, expected)| found != expected) {
+                return Err(ParserError::UnexpectedBytes {
+                    spec_name: self.get_meta_data().get_name().to_name_string(),
+                    offset,
+                    expected: self.bytes.clone(),
+                    found: bytes,
+                });
+            }
+            if update_info {
+                return Ok(Value::None);
+            }
+            Ok(Value::U8Vec(bytes))
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for ExactBytesSpec{
+        async fn serialize(
+            &self,
+            _info_provider: &(dyn InfoProvider + Send + Sync), _mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,            
+        ) -> Result<(), ParserError>
+        {
+            writer.write_data_bytes(&self.bytes).await
+        }
+    }
+
+    /// Amount of padding represented by `PaddingSpec`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Padding{
+        /// Fixed number of bytes e.g reserved bytes of a header
+        Fixed(u32),
+
+        /// Bytes required to move the offset to the next multiple of the alignment e.g XDR fields aligned to 4 bytes
+        AlignTo(u32),
+    }
+
+    impl Padding{
+        /// Returns the number of padding bytes at `offset`
+        pub fn size_at(&self, offset: usize) -> usize {
+            match self {
+                Padding::Fixed(size) => *size as usize,
+                Padding::AlignTo(alignment) if *alignment <= 1 => 0,
+                Padding::AlignTo(alignment) => {
+                    let alignment = *alignment as usize;
+                    (alignment - offset % alignment) % alignment
+                }
+            }
+        }
+    }
+
+    /// Spec to represent padding bytes. The bytes are skipped during deserialization and
+    /// zero bytes are written during serialization
+    pub struct PaddingSpec{
+        spec_meta_data: SpecMetaData,
+        padding: Padding,
+    }
+
+    impl PaddingSpec{
+        pub fn new(name: SpecName, padding: Padding, optional: bool) -> Self{
+            PaddingSpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::None, optional),
+                padding,
+            }
+        }
+    }
+
+    impl Spec for PaddingSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for PaddingSpec{
+        async fn deserialize(
+            &self,
+            _info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, _update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let size = self.padding.size_at(reader.offset());
+            if size > 0 {
+                reader.read_bytes(ReadBytesSize::Fixed(size as u32)).await?
+                    .ok_or_else(|| ParserError::MissingValue(format!(
+                        "Unable to read {} padding bytes for placeholder: {:?}",
+                        size, self.get_meta_data().get_name().to_name_string()
+                    )))?;
+            }
+            Ok(Value::None)
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for PaddingSpec{
+        async fn serialize(
+            &self,
+            _info_provider: &(dyn InfoProvider + Send + Sync), _mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,            
+        ) -> Result<(), ParserError>
+        {
+            let size = self.padding.size_at(writer.offset());
+            writer.write_data_bytes(&vec![0; size]).await
+        }
+    }
+
+    /// Spec to represent bytes of data with fixed size. e.g request body/payload
+    pub struct NBytesSpec{
+        spec_meta_data: SpecMetaData,
+        size: u32,
+    }
+
+    impl  NBytesSpec{
+        pub fn new(name: SpecName, size: u32, optional: bool) -> Self {
+            NBytesSpec {
+                spec_meta_data: SpecMetaData::new(name, ValueType::U8Vec, optional),
+                size,
+            }
+        }
+    }
+
+    impl SimpleValueSpec for NBytesSpec{}
+
+    impl Spec for NBytesSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for NBytesSpec{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let bytes = reader.read_bytes(ReadBytesSize::Fixed(self.size)).await?;
+            if let Some(bytes) = bytes {
+                if update_info {
+                    if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
+                        info_provider.add_info(spec_name, Value::U8Vec(bytes.clone()))?;
+                    }
+                }
+                return Ok(ValueType::parse(&self.get_meta_data().value_type, &bytes));
+            } else {
+                Err(ParserError::MissingValue(format!(
+                    "Unable to read {} bytes for placeholder: {:?}",
+                    self.size, self.get_meta_data().get_name().to_name_string()
+                )))
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for NBytesSpec {
+        async fn serialize(
+            &self,
+            info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,            
+        ) -> Result<(), ParserError>
+        {
+            
+            //mapper_context.start_spec(self);
+            let name = self.get_meta_data().get_name().to_name_string();
+            let value = info_provider.get_info_by_spec_path(&mapper_context.get_current_spec_path());
+            write_data(name, value, self.get_meta_data().is_optional(), writer).await?;
+            Ok(())
+        }
+    }
+
+    
+    /// Spec to represent bytes of data that is available till the end of stream. e.g request body/payload
+    pub struct AllBytesSpec{     
+        spec_meta_data: SpecMetaData,           
+    }
+
+    impl AllBytesSpec{
+        pub fn new(name: SpecName, optional: bool) -> Self {
+            AllBytesSpec {
+                spec_meta_data: SpecMetaData::new(name, ValueType::U8Vec, optional),
+            }
+        }
+    }
+
+    impl Spec for AllBytesSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    impl SimpleValueSpec for AllBytesSpec{}
+
+    #[async_trait]
+    impl SpecDeserialize for AllBytesSpec{    
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,   
+        ) -> Result<Value, ParserError>
+        {
+            let bytes = reader.read_bytes(ReadBytesSize::Full).await?;
+            if let Some(bytes) = bytes {
+                if update_info{
+                    if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
+                        info_provider.add_info(spec_name, Value::U8Vec(bytes.clone()))?;
+                    }
+                    return Ok(Value::None)
+                }
+                return Ok(ValueType::parse(self.get_meta_data().get_value_type(), &bytes));
+            } else {
+                Err(ParserError::MissingValue(format!(
+                    "Unable to read {} bytes for placeholder: {:?}",
+                    "remaining ", self.get_meta_data().name.to_name_string()
+                )))
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for AllBytesSpec{
+        async fn serialize(
+            &self,
+            info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,            
+        ) -> Result<(), ParserError>
+        {
+            let name = self.get_meta_data().get_name().to_name_string();                        
             //mapper_context.start_spec(self);
+            let value = info_provider.get_info_by_spec_path(&mapper_context.get_current_spec_path());
+            write_data(name, value, self.get_meta_data().is_optional(), writer).await?;
+            Ok(())
+        }
+    }
+
+    /// Encoding of the length or count field referred by `LengthFromFieldSpec` and `RepeatCount::FromField`. 
+    /// The encoding is used when the field is computed during serialization
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum LengthEncoding{
+
+        /// length is a binary number of the given value type e.g ValueType::UnSignedNumber16
+        Binary(ValueType),
+
+        /// length is ascii decimal digits e.g Content-Length header in http
+        Decimal,
+    }
+
+    /// Spec to represent string or bytes whose size is available in a previously parsed field e.g http body whose size
+    /// is in Content-Length header. The length field is looked up by name using `InfoProvider::get_info`.
+    /// During serialization the length field is populated with the size of the data if it is not already available 
+    pub struct LengthFromFieldSpec{
+        spec_meta_data: SpecMetaData,
+        length_field: String,
+        length_encoding: LengthEncoding,
+    }
+
+    impl LengthFromFieldSpec{
+
+        /// Creates spec for string data whose length is in field `length_field`
+        pub fn new_string(name: SpecName, length_field: String, length_encoding: LengthEncoding, optional: bool) -> Self {
+            LengthFromFieldSpec {
+                spec_meta_data: SpecMetaData::new(name, ValueType::String, optional),
+                length_field,
+                length_encoding,
+            }
+        }
+
+        /// Creates spec for bytes whose length is in field `length_field`
+        pub fn new_bytes(name: SpecName, length_field: String, length_encoding: LengthEncoding, optional: bool) -> Self {
+            LengthFromFieldSpec {
+                spec_meta_data: SpecMetaData::new(name, ValueType::U8Vec, optional),
+                length_field,
+                length_encoding,
+            }
+        }
+
+        /// Converts value of length field to length. Both binary numbers and decimal strings are accepted
+        fn get_length(&self, value: &Value) -> Result<u32, ParserError> {
+            value_as_count(value).ok_or_else(|| ParserError::InvalidValue {
+                spec_path: self.length_field.clone(),
+                value: format!("{:?}", value),
+                message: format!("length field of {} is not a valid length", self.spec_meta_data.get_name().to_name_string()),
+            })
+        }
+    }
+
+    /// Number of bytes of `value` when it is written e.g value of a length prefix or Content-Length
+    fn byte_length(value: &Value) -> Result<usize, ParserError> {
+        match value {
+            Value::String(data) => Ok(data.len()),
+            Value::U8Vec(data) => Ok(data.len()),
+            Value::None => Ok(0),
+            value => Ok(value.to_bytes(Endianness::Big)?.len()),
+        }
+    }
+
+    /// Returns true if the value derived from other data e.g length of a payload is not yet available. Available value
+    /// that does not match the derived value is reported as invalid, numbers and decimal strings are compared as numbers
+    fn is_derived_value_missing(spec_path: &str, existing: Option<&Value>, derived: &Value) -> Result<bool, ParserError> {
+        match existing {
+            None | Some(Value::None) => Ok(true),
+            Some(existing) if existing == derived => Ok(false),
+            Some(existing) if value_as_count(existing).is_some() && value_as_count(existing) == value_as_count(derived) => Ok(false),
+            Some(existing) => Err(ParserError::InvalidValue {
+                spec_path: spec_path.to_owned(),
+                value: format!("{:?}", existing),
+                message: format!("value does not match {:?} derived from the data", derived),
+            }),
+        }
+    }
+
+    /// Converts value of a length or count field to u32. Both binary numbers and decimal strings are accepted
+    fn value_as_count(value: &Value) -> Option<u32> {
+        match value {
+            Value::String(length) => length.trim().parse::<u32>().ok(),
+            Value::UnSignedNumber8(length) => Some(*length as u32),
+            Value::UnSignedNumber16(length) => Some(*length as u32),
+            Value::UnSignedNumber32(length) => Some(*length),
+            Value::UnSignedNumber64(length) => u32::try_from(*length).ok(),
+            Value::SignedNumber8(length) => u32::try_from(*length).ok(),
+            Value::SignedNumber16(length) => u32::try_from(*length).ok(),
+            Value::SignedNumber32(length) => u32::try_from(*length).ok(),
+            Value::SignedNumber64(length) => u32::try_from(*length).ok(),
+            _ => None,
+        }
+    }
+
+    impl LengthEncoding{
+
+        /// Converts length to value of the length field `field` based on the encoding
+        fn to_value(&self, field: &str, length: usize) -> Result<Value, ParserError> {
+            let invalid_length = || ParserError::InvalidValue {
+                spec_path: field.to_owned(),
+                value: length.to_string(),
+                message: "length does not fit in length field".to_string(),
+            };
+            let value = match self {
+                LengthEncoding::Decimal => Value::String(length.to_string()),
+                LengthEncoding::Binary(ValueType::UnSignedNumber8) => Value::UnSignedNumber8(u8::try_from(length).map_err(|_| invalid_length())?),
+                LengthEncoding::Binary(ValueType::UnSignedNumber16) => Value::UnSignedNumber16(u16::try_from(length).map_err(|_| invalid_length())?),
+                LengthEncoding::Binary(ValueType::UnSignedNumber32) => Value::UnSignedNumber32(u32::try_from(length).map_err(|_| invalid_length())?),
+                LengthEncoding::Binary(ValueType::UnSignedNumber64) => Value::UnSignedNumber64(length as u64),
+                LengthEncoding::Binary(ValueType::SignedNumber16) => Value::SignedNumber16(i16::try_from(length).map_err(|_| invalid_length())?),
+                LengthEncoding::Binary(ValueType::SignedNumber32) => Value::SignedNumber32(i32::try_from(length).map_err(|_| invalid_length())?),
+                LengthEncoding::Binary(ValueType::SignedNumber64) => Value::SignedNumber64(i64::try_from(length).map_err(|_| invalid_length())?),
+                LengthEncoding::Binary(_) => return Err(ParserError::InvalidValue {
+                    spec_path: field.to_owned(),
+                    value: length.to_string(),
+                    message: "length encoding must be an integer value type".to_string(),
+                }),
+            };
+            Ok(value)
+        }
+    }
+
+    /// Spec to represent one of several branches selected by the value of a previously parsed discriminator field 
+    /// e.g opcode of a binary message. Exactly one branch is deserialized. During serialization the branch is selected
+    /// using the stored value of the discriminator field
+    pub struct SwitchSpec{
+        spec_meta_data: SpecMetaData,
+        discriminator_field: String,
+        pub(crate) cases: Vec<(Value, ListSpec)>,
+        pub(crate) default_case: Option<ListSpec>,
+    }
+
+    impl SwitchSpec{
+        pub fn new(name: SpecName, discriminator_field: String, cases: Vec<(Value, ListSpec)>, default_case: Option<ListSpec>, optional: bool) -> Self{
+            SwitchSpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::None, optional),
+                discriminator_field,
+                cases,
+                default_case,
+            }
+        }
+
+        /// Selects the branch whose case value matches the discriminator value. Falls back to default branch if available
+        fn select_branch(&self, discriminator: &Value) -> Result<&ListSpec, ParserError>{
+            self.cases.iter()
+                .find(|(case, _)| case == discriminator)
+                .map(|(_, branch)| branch)
+                .or(self.default_case.as_ref())
+                .ok_or_else(|| ParserError::InvalidValue {
+                    spec_path: self.discriminator_field.clone(),
+                    value: format!("{:?}", discriminator),
+                    message: format!("no case of switch spec {} matches the discriminator", self.get_meta_data().get_name().to_name_string()),
+                })
+        }
+
+        fn missing_discriminator(&self) -> ParserError{
+            ParserError::MissingValue(format!(
+                "discriminator field {} is missing for switch spec: {:?}",
+                self.discriminator_field, self.get_meta_data().get_name().to_name_string()
+            ))
+        }
+    }
+
+    impl Spec for SwitchSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for SwitchSpec{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let discriminator = get_deserialized_field(info_provider, &self.discriminator_field, update_info).cloned()
+                .ok_or_else(|| self.missing_discriminator())?;
+            let branch = self.select_branch(&discriminator)?;
+            undoable_deserialize(branch, info_provider, reader, update_info).await
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for SwitchSpec{
+        async fn serialize(
+            &self,
+            info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,            
+        ) -> Result<(), ParserError>
+        {
+            let discriminator = info_provider.get_mapper().get_value_by_key_at(&self.discriminator_field, &mapper_context.get_repeat_indexes())
+                .or_else(|| info_provider.get_info(&self.discriminator_field))
+                .ok_or_else(|| self.missing_discriminator())?;
+            let branch = self.select_branch(discriminator)?;
+            serialize(branch, info_provider, writer, mapper_context).await
+        }
+
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            let discriminator = with_mapper_context(info_provider, mapper_context, |info_provider| {
+                info_provider.get_info(&self.discriminator_field).cloned()
+            });
+            match discriminator {
+                Some(discriminator) => prepare(self.select_branch(&discriminator)?, info_provider, mapper_context),
+                // serialization reports the missing discriminator
+                None => Ok(()),
+            }
+        }
+    }
+
+    /// Composite spec to represent ordered alternatives e.g different replies of a text protocol. Alternatives are tried in order
+    /// and the first alternative that deserializes successfully is used. Reader is reset and data added by a failed alternative is
+    /// discarded before trying the next alternative. Name of the matched alternative is recorded using the name of this spec.
+    /// When InfoProvider is not updated, a map of the matched alternative name to its value is returned
+    pub struct OneOfSpecs{
+        spec_meta_data: SpecMetaData,
+        pub(crate) alternatives: Vec<ListSpec>,
+    }
+
+    impl OneOfSpecs{
+        pub fn new(name: SpecName, alternatives: Vec<ListSpec>, optional: bool) -> Self{
+            OneOfSpecs{
+                spec_meta_data: SpecMetaData::new(name, ValueType::String, optional),
+                alternatives,
+            }
+        }
+
+        /// Selects the alternative to serialize. Recorded alternative is used if available, otherwise the first alternative 
+        /// that has data is used
+        fn select_alternative(&self, info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &mut MapperContext) -> Result<&ListSpec, ParserError>{
+            if let Some(Value::String(selected)) = info_provider.get_info_by_spec_path(&mapper_context.get_current_spec_path()) {
+                if let Some(alternative) = self.alternatives.iter()
+                    .find(|alternative| &alternative.get_meta_data().get_name().to_name_string() == selected) {
+                    return Ok(alternative);
+                }
+            }
+            for alternative in &self.alternatives {
+                mapper_context.start_spec(alternative);
+                let alternative_path = format!("{}.", mapper_context.get_current_spec_path());
+                mapper_context.end_spec(alternative)?;
+                if info_provider.get_mapper().get_spec_data().keys().any(|key| key.starts_with(&alternative_path)) {
+                    return Ok(alternative);
+                }
+            }
+            Err(ParserError::MissingData(format!("no alternative of spec {} has data", self.get_meta_data().get_name().to_name_string())))
+        }
+    }
+
+    impl Spec for OneOfSpecs{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for OneOfSpecs{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let mut errors = vec![];
+            for alternative in &self.alternatives {
+                let alternative_name = alternative.get_meta_data().get_name().to_name_string();
+                // data added by a failed alternative is discarded
+                let savepoint = info_provider.get_mapper_context().start_recording();
+                let marker = reader.mark();
+                let result = SpecDeserializer{ inner: alternative }.deserialize(info_provider, reader, update_info).await;
+                if result.is_err() {
+                    undo_changes(info_provider.get_mapper_mut(), savepoint);
+                }
+                info_provider.get_mapper_context().stop_recording();
+                match result {
+                    Ok(value) => {
+                        reader.unmark(&marker)?;
+                        if !update_info {
+                            return Ok(Value::Map(HashMap::from([(alternative_name, value)])));
+                        }
+                        let selected = Value::String(alternative_name);
+                        if let SpecName::Name(name) = self.get_meta_data().get_name() {
+                            info_provider.add_info(name.clone(), selected)?;
+                        }
+                        return Ok(Value::None);
+                    },
+                    Err(error) => {
+                        debug!("alternative {} of {} failed: {}", alternative_name, self.get_meta_data().get_name(), error);
+                        reader.reset(&marker)?;
+                        errors.push((alternative_name, error));
+                    },
+                }
+            }
+            Err(ParserError::NoMatchingAlternative { spec_name: self.get_meta_data().get_name().to_name_string(), errors })
+        }
+    }
+
+    #[async_trait]
+    impl SpecSerialize for OneOfSpecs{
+        async fn serialize(
+            &self,
+            info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,            
+        ) -> Result<(), ParserError>
+        {
+            let alternative = self.select_alternative(info_provider, mapper_context)?;
+            serialize(alternative, info_provider, writer, mapper_context).await
+        }
+
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            match self.select_alternative(info_provider, mapper_context) {
+                Ok(alternative) => prepare(alternative, info_provider, mapper_context),
+                // serialization reports the missing data
+                Err(_) => Ok(()),
+            }
+        }
+    }
+
+    /// Sub field of `BitFieldSpec` that is `width` bits wide. Value of the sub field is stored using `name`
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct BitField{
+        pub name: String,
+        pub width: u8,
+    }
+
+    impl BitField{
+        pub fn new(name: &str, width: u8) -> Self{
+            BitField{
+                name: name.to_owned(),
+                width,
+            }
+        }
+    }
+
+    /// Composite spec to represent sub-byte fields packed into a u8, u16 or u32 word e.g DNS flags, MQTT fixed header.
+    /// Fields are laid out from the most significant bit of the word, bits left after the last field are ignored
+    /// while reading and written as zero. Each field is stored as a separate value of the same type as the word
+    pub struct BitFieldSpec{
+        spec_meta_data: SpecMetaData,
+        endianness: Endianness,
+        pub(crate) fields: Vec<BitField>,
+    }
+
+    impl BitFieldSpec{
+        pub fn new(name: SpecName, word_type: ValueType, endianness: Endianness, fields: Vec<BitField>, optional: bool) -> Self{
+            BitFieldSpec{
+                spec_meta_data: SpecMetaData::new(name, word_type, optional),
+                endianness,
+                fields,
+            }
+        }
+
+        fn invalid_layout(&self, message: String) -> ParserError{
+            ParserError::InvalidValue {
+                spec_path: self.get_meta_data().get_name().to_name_string(),
+                value: format!("{:?}", self.fields),
+                message,
+            }
+        }
+
+        /// Returns number of bits in the word after validating that the fields fit into the word
+        fn word_bits(&self) -> Result<u32, ParserError>{
+            let bits = match self.get_meta_data().get_value_type() {
+                ValueType::UnSignedNumber8 => 8,
+                ValueType::UnSignedNumber16 => 16,
+                ValueType::UnSignedNumber32 => 32,
+                value_type => return Err(self.invalid_layout(format!("bit fields are not supported for {:?}", value_type))),
+            };
+            let used_bits: u32 = self.fields.iter().map(|field| field.width as u32).sum();
+            if used_bits > bits || self.fields.iter().any(|field| field.width == 0) {
+                return Err(self.invalid_layout(format!("bit fields do not fit into {} bits", bits)));
+            }
+            Ok(bits)
+        }
+
+        /// Returns shift and mask of each field within the word
+        fn field_layout(&self, bits: u32) -> Vec<(&BitField, u32, u32)>{
+            let mut consumed = 0;
+            self.fields.iter().map(|field| {
+                consumed += field.width as u32;
+                let mask = if field.width >= 32 { u32::MAX } else { (1u32 << field.width) - 1 };
+                (field, bits - consumed, mask)
+            }).collect()
+        }
+
+        /// Converts field or word to value of the word type
+        fn to_value(&self, number: u32) -> Value{
+            match self.get_meta_data().get_value_type() {
+                ValueType::UnSignedNumber8 => Value::UnSignedNumber8(number as u8),
+                ValueType::UnSignedNumber16 => Value::UnSignedNumber16(number as u16),
+                _ => Value::UnSignedNumber32(number),
+            }
+        }
+
+        fn field_path(mapper_context: &MapperContext, field: &BitField) -> String{
+            format!("{}.{}", mapper_context.get_current_spec_path(), field.name)
+        }
+
+        /// Returns value of each field. None is returned when the spec is optional and none of the fields are
+        /// available. A word with only some of the fields available can not be written and is reported as missing data
+        fn field_values<'a>(&self, info_provider: &'a (dyn InfoProvider + Send + Sync), mapper_context: &MapperContext, bits: u32) -> Result<Option<Vec<&'a Value>>, ParserError>{
+            let values: Vec<(&BitField, Option<&Value>)> = self.field_layout(bits).into_iter()
+                .map(|(field, _, _)| (field, info_provider.get_info_by_spec_path(&Self::field_path(mapper_context, field))))
+                .collect();
+            if self.get_meta_data().is_optional() && values.iter().all(|(_, value)| value.is_none()) {
+                return Ok(None);
+            }
+            values.into_iter()
+                .map(|(field, value)| value.ok_or_else(|| ParserError::MissingData(field.name.clone())))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Some)
+        }
+    }
+
+    impl Spec for BitFieldSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for BitFieldSpec{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
+        {
+            let bits = self.word_bits()?;
+            let bytes = reader.read_bytes(ReadBytesSize::Fixed(bits / 8)).await?;
+            let Some(bytes) = bytes else {
+                return Err(ParserError::MissingValue(format!(
+                    "Unable to read {} bytes for placeholder: {:?}",
+                    bits / 8, self.get_meta_data().get_name().to_name_string()
+                )));
+            };
+            let word = ValueType::parse_with_endianness(self.get_meta_data().get_value_type(), &bytes, self.endianness);
+            let word = value_as_count(&word).unwrap_or_default();
+            let fields = self.field_layout(bits).into_iter()
+                .map(|(field, shift, mask)| (field.name.clone(), self.to_value((word >> shift) & mask)));
+            if !update_info {
+                return Ok(Value::Map(fields.collect()));
+            }
+            for (name, value) in fields {
+                info_provider.add_info(name, value)?;
+            }
+            Ok(Value::None)
+        }
+    }
 
-            let name = self.get_meta_data().get_name().to_name_string();
-            if let SpecName::Delimiter = self.get_meta_data().get_name() {
-                write_data(name, Some(&Value::String(self.input.to_owned())), 
-                    self.get_meta_data().is_optional(), 
-                    writer).await?;
-            }else{
-                let value = info_provider.get_info(&mapper_context.get_current_spec_path());
-                write_data(name, value, self.get_meta_data().is_optional(), writer).await?;
+    #[async_trait]
+    impl SpecSerialize for BitFieldSpec{
+        async fn serialize(
+            &self,
+            info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,            
+        ) -> Result<(), ParserError>
+        {
+            let bits = self.word_bits()?;
+            let Some(values) = self.field_values(info_provider, mapper_context, bits)? else {
+                return Ok(());
+            };
+            // bits after the last field are always written as zero
+            let mut word = 0u32;
+            for ((field, shift, mask), value) in self.field_layout(bits).into_iter().zip(values) {
+                let field_path = Self::field_path(mapper_context, field);
+                let number = match value {
+                    Value::Bool(flag) => Some(*flag as u32),
+                    value => value_as_count(value),
+                };
+                match number {
+                    Some(number) if number <= mask => word |= number << shift,
+                    _ => return Err(ParserError::InvalidValue {
+                        spec_path: field_path,
+                        value: format!("{:?}", value),
+                        message: format!("value does not fit into {} bits", field.width),
+                    }),
+                }
             }
-            
-            
-            
+            writer.write_data_bytes(&self.to_value(word).to_bytes(self.endianness)?).await
+        }
 
-            Ok(())
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            // partially available word is reported even if the spec is optional
+            self.field_values(info_provider, mapper_context, self.word_bits()?).map(|_| ())
         }
     }
 
+    /// Handling of values that are not available in the table of `EnumMapSpec`
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum UnmappedValue{
 
-    ///Key value spec. It is a composite spec(it contains other Spec) containing Key and Value Spec
-    pub(crate) struct KeyValueSpec{
-        pub spec_metadata: SpecMetaData,
-        pub key: Key,
-        pub value: ValueSpec,
+        /// values without a name are reported as `ParserError::InvalidValue`
+        #[default]
+        Fail,
+
+        /// values without a name are stored as read e.g codes reserved for future use. During serialization values of the
+        /// same type as the values in the table are written as is
+        PassThrough,
     }
-    
-    impl KeyValueSpec{
-        pub fn new(key: Key, value: ValueSpec, spec_metadata: SpecMetaData) -> Self {
-            KeyValueSpec {
-                spec_metadata,
-                key,
-                value,
+
+    /// Spec to map the number or string read by an `inner` spec to a symbolic name e.g opcodes, status codes.
+    /// Name of the value is stored in the InfoProvider and the name is mapped back to the value during serialization.
+    /// Values are matched using equality, so the values in the table must be of the type read by the inner spec
+    pub struct EnumMapSpec{
+        spec_meta_data: SpecMetaData,
+        inner: Box<dyn ProtocolSpec>,
+        table: Vec<(Value, String)>,
+        unmapped: UnmappedValue,
+    }
+
+    impl EnumMapSpec{
+        pub fn new(name: SpecName, inner: Box<dyn ProtocolSpec>, table: Vec<(Value, String)>, unmapped: UnmappedValue, optional: bool) -> Self{
+            EnumMapSpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::String, optional),
+                inner,
+                table,
+                unmapped,
+            }
+        }
+
+        fn unmapped_error(&self, spec_path: String, value: &Value, message: &str) -> ParserError{
+            ParserError::InvalidValue {
+                spec_path,
+                value: format!("{:?}", value),
+                message: format!("{} of {}", message, self.get_meta_data().get_name().to_name_string()),
+            }
+        }
+
+        /// Converts value read by the inner spec to its name
+        fn to_name(&self, spec_path: String, value: Value) -> Result<Value, ParserError>{
+            match self.table.iter().find(|(code, _)| *code == value) {
+                Some((_, name)) => Ok(Value::String(name.clone())),
+                None if self.unmapped == UnmappedValue::PassThrough => Ok(value),
+                None => Err(self.unmapped_error(spec_path, &value, "value is not available in the table")),
             }
         }
+
+        /// Converts name to the value written by the inner spec
+        fn to_code(&self, spec_path: String, value: &Value) -> Result<Value, ParserError>{
+            let code = self.table.iter()
+                .find(|(_, name)| matches!(value, Value::String(value) if value == name))
+                .map(|(code, _)| code.clone());
+            match code {
+                Some(code) => Ok(code),
+                None if self.unmapped == UnmappedValue::PassThrough && self.is_code(value) => Ok(value.clone()),
+                None if self.unmapped == UnmappedValue::PassThrough => Err(self.unmapped_error(spec_path, value, "value is neither a name nor a value of the table")),
+                None => Err(self.unmapped_error(spec_path, value, "name is not available in the table")),
+            }
+        }
+
+        /// Whether `value` is of the same type as the values in the table, so that it can be passed through to the inner spec
+        fn is_code(&self, value: &Value) -> bool{
+            self.table.iter().all(|(code, _)| std::mem::discriminant(code) == std::mem::discriminant(value))
+        }
     }
 
-    pub(crate) fn extract_name_and_spec_path<F, S> (
-        spec_path_finder: F,
-        mapper: &mut dyn Mapper, spec: &S, inner_spec: &dyn ProtocolSpec ) -> Result<(Option<String>, Option<String>), ParserError>
-        where F: Fn(&dyn Mapper) -> String,
-        S: ProtocolSpec,
+    impl Spec for EnumMapSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
+        }
+    }
+
+    impl SimpleValueSpec for EnumMapSpec{}
+
+    #[async_trait]
+    impl SpecDeserialize for EnumMapSpec{
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
         {
-        mapper.get_mapper_context_mut().start_spec_type(spec.to_spec_type());
-        mapper.get_mapper_context_mut().start_spec_type(inner_spec.to_spec_type());
-        let spec_name = mapper.get_mapper_context().get_last_available_spec_name();
-        let spec_path = Some(
-                spec_path_finder(mapper)                
-            );
-        mapper.get_mapper_context_mut().end_spec(inner_spec)?;
-        mapper.get_mapper_context_mut().end_spec(spec)?;
-        Ok((spec_name, spec_path))
+            let value = self.inner.deserialize(info_provider, reader, false).await?;
+            let value = self.to_name(info_provider.get_mapper_context().get_current_spec_path(), value)?;
+            if update_info {
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
+                    info_provider.add_info(spec_name, value)?;
+                }
+                return Ok(Value::None);
+            }
+            Ok(value)
+        }
     }
 
+    /// Info provider that returns `value` for `spec_path` and forwards other lookups to `info_provider`. This allows an inner spec 
+    /// to serialize a value that is different from the value available in the InfoProvider. `info_provider` is only borrowed
+    /// for reading during serialization, so changes made through `get_mapper_mut` go to `scratch_mapper` and are discarded
+    struct OverriddenInfoProvider<'a>{
+        info_provider: &'a (dyn InfoProvider + Send + Sync),
+        spec_path: String,
+        value: Value,
+        scratch_mapper: DefaultMapper,
+    }
 
-    impl Spec for KeyValueSpec{
-        fn get_meta_data(&self) -> &SpecMetaData{
-            &self.spec_metadata
+    impl <'a> OverriddenInfoProvider<'a>{
+        fn new(info_provider: &'a (dyn InfoProvider + Send + Sync), spec_path: String, value: Value) -> Self{
+            OverriddenInfoProvider{ info_provider, spec_path, value, scratch_mapper: DefaultMapper::new() }
         }
     }
 
+    impl InfoProvider for OverriddenInfoProvider<'_>{
+        fn get_info(&self, key: &str) -> Option<&Value>{
+            self.info_provider.get_info(key)
+        }
+
+        fn get_info_by_spec_path(&self, spec_path: &str) -> Option<&Value>{
+            if spec_path == self.spec_path {
+                Some(&self.value)
+            } else {
+                self.info_provider.get_info_by_spec_path(spec_path)
+            }
+        }
+
+        fn get_mapper_mut(&mut self) ->&mut dyn Mapper {
+            &mut self.scratch_mapper
+        }
 
+        fn get_mapper(&self) ->&dyn Mapper {
+            self.info_provider.get_mapper()
+        }
+    }
 
     #[async_trait]
-    impl SpecSerialize for KeyValueSpec {
+    impl SpecSerialize for EnumMapSpec{
         async fn serialize(
             &self,
             info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &mut MapperContext,
             writer: &mut dyn SpecWrite,            
         ) -> Result<(), ParserError>
         {
-            serialize(&self.key, info_provider, writer, mapper_context).await?;
-            serialize(&self.value, info_provider, writer, mapper_context).await?;            
-            Ok(())
+            let spec_path = mapper_context.get_current_spec_path();
+            let Some(value) = info_provider.get_info_by_spec_path(&spec_path) else {
+                // inner spec reports the missing data
+                return self.inner.serialize(info_provider, mapper_context, writer).await;
+            };
+            let code = self.to_code(spec_path.clone(), value)?;
+            let info_provider = OverriddenInfoProvider::new(info_provider, spec_path, code);
+            self.inner.serialize(&info_provider, mapper_context, writer).await
+        }
+
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            self.inner.prepare_serialize(info_provider, mapper_context)
+        }
+    }
+
+    /// Algorithm used by `ChecksumSpec` to compute the checksum of the covered bytes
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum ChecksumAlgorithm{
+
+        /// 8 bit sum of the bytes ignoring overflow
+        Sum8,
+
+        /// 8 bit xor of the bytes e.g NMEA sentences
+        Xor8,
+
+        /// CRC-16/MODBUS, written as little-endian in Modbus RTU frames
+        Crc16Modbus,
+
+        /// CRC-32/ISO-HDLC used by Ethernet, zip and PNG
+        Crc32,
+    }
+
+    impl ChecksumAlgorithm{
+
+        /// Computes checksum of `data`
+        pub fn compute(&self, data: &[u8]) -> u32{
+            match self {
+                ChecksumAlgorithm::Sum8 => data.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte)) as u32,
+                ChecksumAlgorithm::Xor8 => data.iter().fold(0u8, |xor, byte| xor ^ byte) as u32,
+                ChecksumAlgorithm::Crc16Modbus => Self::reflected_crc(data, 0xA001, 0xFFFF, 0),
+                ChecksumAlgorithm::Crc32 => Self::reflected_crc(data, 0xEDB8_8320, 0xFFFF_FFFF, 0xFFFF_FFFF),
+            }
+        }
+
+        fn reflected_crc(data: &[u8], polynomial: u32, init: u32, xor_out: u32) -> u32{
+            let crc = data.iter().fold(init, |crc, byte| {
+                (0..8).fold(crc ^ *byte as u32, |crc, _| if crc & 1 == 1 { (crc >> 1) ^ polynomial } else { crc >> 1 })
+            });
+            crc ^ xor_out
+        }
+
+        /// Type of the checksum value written on the wire
+        fn value_type(&self) -> ValueType{
+            match self {
+                ChecksumAlgorithm::Sum8 | ChecksumAlgorithm::Xor8 => ValueType::UnSignedNumber8,
+                ChecksumAlgorithm::Crc16Modbus => ValueType::UnSignedNumber16,
+                ChecksumAlgorithm::Crc32 => ValueType::UnSignedNumber32,
+            }
+        }
+
+        /// Number of bytes of the checksum value
+        fn size(&self) -> u32{
+            match self {
+                ChecksumAlgorithm::Sum8 | ChecksumAlgorithm::Xor8 => 1,
+                ChecksumAlgorithm::Crc16Modbus => 2,
+                ChecksumAlgorithm::Crc32 => 4,
+            }
+        }
+
+        fn checksum_value(&self, checksum: u32) -> Value{
+            match self.value_type() {
+                ValueType::UnSignedNumber8 => Value::UnSignedNumber8(checksum as u8),
+                ValueType::UnSignedNumber16 => Value::UnSignedNumber16(checksum as u16),
+                _ => Value::UnSignedNumber32(checksum),
+            }
+        }
+    }
+
+    /// Composite spec to represent constituents followed by a checksum of their bytes e.g Modbus RTU frame, PNG chunk.
+    /// The checksum is verified during deserialization and computed during serialization. Checksum read is recorded 
+    /// using the name of this spec
+    pub struct ChecksumSpec{
+        spec_meta_data: SpecMetaData,
+        pub(crate) covered: ListSpec,
+        algorithm: ChecksumAlgorithm,
+        endianness: Endianness,
+    }
+
+    impl ChecksumSpec{
+        pub fn new(name: SpecName, covered: ListSpec, algorithm: ChecksumAlgorithm, endianness: Endianness, optional: bool) -> Self{
+            ChecksumSpec{
+                spec_meta_data: SpecMetaData::new(name, algorithm.value_type(), optional),
+                covered,
+                algorithm,
+                endianness,
+            }
+        }
+    }
+
+    impl Spec for ChecksumSpec{
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.spec_meta_data
         }
     }
 
     #[async_trait]
-    impl SpecDeserialize for KeyValueSpec{
+    impl SpecDeserialize for ChecksumSpec{
         async fn deserialize(
             &self,
             info_provider: &mut (dyn InfoProvider + Send + Sync),
-            reader: &mut dyn SpecRead, update_info: bool,            
-    ) -> Result<Value, ParserError>
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError>
         {
-            let path_finder =  |mapper:  &dyn Mapper| {mapper.get_mapper_context().get_current_spec_path()};
-            let ( key_spec_name,  key_spec_path,) = extract_name_and_spec_path(path_finder, info_provider.get_mapper_mut(), &self.key, &self.key.0)?;
-            let key_name = undoable_deserialize(&self.key, info_provider, reader, false).await?;            
-            let ( value_spec_name,  value_spec_path,) = extract_name_and_spec_path(path_finder,info_provider.get_mapper_mut(), &self.value, &self.value.0)?;           
-            let value = undoable_deserialize(&self.value, info_provider, reader, false).await?;
-            match (key_spec_path, value_spec_path){
-                (None, None) => {},
-                (None, Some(ref value_spec_path)) => {
-                    info_provider.get_mapper_mut().get_spec_data_mut().insert(value_spec_path.clone(), value);
-                },
-                (Some(ref key_spec_path), None) => {
-                    info_provider.get_mapper_mut().get_spec_data_mut().insert(key_spec_path.clone(), key_name);
-                },
-                (Some(ref _key_spec_path), Some(ref _value_spec_path)) => {
-                    if update_info{
-                        info_provider.get_mapper_mut().add_to_key_value_list(key_name.get_string_value_unchecked().unwrap(),
-                            value, key_spec_name.unwrap(), value_spec_name.unwrap())?;
-                    }
-                },
+            let marker = reader.mark();
+            let result = SpecDeserializer{ inner: &self.covered }.deserialize(info_provider, reader, update_info).await;
+            let covered_bytes = reader.marked_bytes(&marker);
+            reader.unmark(&marker)?;
+            let covered = result?;
+            let expected = self.algorithm.compute(&covered_bytes?);
+
+            let size = self.algorithm.size();
+            let Some(bytes) = reader.read_bytes(ReadBytesSize::Fixed(size)).await? else {
+                return Err(ParserError::MissingValue(format!(
+                    "Unable to read {} bytes for placeholder: {:?}",
+                    size, self.get_meta_data().get_name().to_name_string()
+                )));
+            };
+            let checksum = ValueType::parse_with_endianness(self.get_meta_data().get_value_type(), &bytes, self.endianness);
+            let actual = value_as_count(&checksum).unwrap_or_default();
+            if actual != expected {
+                return Err(ParserError::ChecksumMismatch { 
+                    spec_name: self.get_meta_data().get_name().to_name_string(), 
+                    expected, 
+                    actual,
+                });
             }
-            return Ok(Value::None);            
+            if !update_info {
+                let mut entries = match covered {
+                    Value::Map(entries) => entries,
+                    _ => HashMap::new(),
+                };
+                if let SpecName::Name(name) = self.get_meta_data().get_name() {
+                    entries.insert(name.clone(), checksum);
+                }
+                return Ok(Value::Map(entries));
+            }
+            if let SpecName::Name(name) = self.get_meta_data().get_name() {
+                info_provider.add_info(name.clone(), checksum)?;
+            }
+            Ok(Value::None)
         }
     }
 
-    /// Spec to represent bytes of data with fixed size. e.g request body/payload
-    pub struct NBytesSpec{
-        spec_meta_data: SpecMetaData,
-        size: u32,
+    #[async_trait]
+    impl SpecSerialize for ChecksumSpec{
+        async fn serialize(
+            &self,
+            info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &mut MapperContext,
+            writer: &mut dyn SpecWrite,            
+        ) -> Result<(), ParserError>
+        {
+            // covered constituents are written to a buffer to compute the checksum
+            let mut buffer = Vec::new();
+            serialize(&self.covered, info_provider, &mut ProtocolBuffWriter::with_offset(&mut buffer, writer.offset()), mapper_context).await?;
+            let checksum = self.algorithm.checksum_value(self.algorithm.compute(&buffer));
+            writer.write_data_bytes(&buffer).await?;
+            writer.write_data_bytes(&checksum.to_bytes(self.endianness)?).await
+        }
+
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            prepare(&self.covered, info_provider, mapper_context)
+        }
     }
 
-    impl  NBytesSpec{
-        pub fn new(name: SpecName, size: u32, optional: bool) -> Self {
-            NBytesSpec {
-                spec_meta_data: SpecMetaData::new(name, ValueType::U8Vec, optional),
-                size,
-            }
+    /// Computes value of a `ComputedSpec` from the value of its source field e.g length of a payload.
+    /// Any closure of type Fn(&Value) -> Result<Value, String> can be used as computer
+    pub trait ValueComputer: Send + Sync{
+
+        /// Returns the computed value or error message if the value can not be computed from `source`
+        fn compute(&self, source: &Value) -> Result<Value, String>;
+    }
+
+    impl <F> ValueComputer for F where F: Fn(&Value) -> Result<Value, String> + Send + Sync{
+        fn compute(&self, source: &Value) -> Result<Value, String> {
+            self(source)
         }
     }
 
-    impl SimpleValueSpec for NBytesSpec{}
+    /// Computes number of bytes of the source value encoded using the length encoding e.g length prefix, Content-Length
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct ByteLength(pub LengthEncoding);
 
-    impl Spec for NBytesSpec{
+    impl ValueComputer for ByteLength{
+        fn compute(&self, source: &Value) -> Result<Value, String> {
+            let length = byte_length(source).map_err(|e| e.to_string())?;
+            self.0.to_value("", length).map_err(|_| format!("length {} does not fit into {:?}", length, self.0))
+        }
+    }
+
+    /// Spec to represent a field whose value is computed from `source_field` during serialization e.g length prefix of 
+    /// a string. The value is computed before serialization starts, so the field is consistent with the source 
+    /// even if the field is written before the source. Value available in the InfoProvider must match the computed value.
+    /// During deserialization the value is read using `inner` spec like any other field
+    pub struct ComputedSpec{
+        spec_meta_data: SpecMetaData,
+        inner: Box<dyn ProtocolSpec>,
+        source_field: String,
+        computer: Box<dyn ValueComputer>,
+    }
+
+    impl ComputedSpec{
+        pub fn new(name: SpecName, inner: Box<dyn ProtocolSpec>, source_field: String, computer: Box<dyn ValueComputer>, optional: bool) -> Self{
+            ComputedSpec{
+                spec_meta_data: SpecMetaData::new(name, inner.get_meta_data().get_value_type().clone(), optional),
+                inner,
+                source_field,
+                computer,
+            }
+        }
+    }
+
+    impl Spec for ComputedSpec{
         fn get_meta_data(&self) -> &SpecMetaData {
             &self.spec_meta_data
         }
     }
 
+    impl SimpleValueSpec for ComputedSpec{}
+
     #[async_trait]
-    impl SpecDeserialize for NBytesSpec{
+    impl SpecDeserialize for ComputedSpec{
         async fn deserialize(
             &self,
             info_provider: &mut (dyn InfoProvider + Send + Sync),
             reader: &mut dyn SpecRead, update_info: bool,
         ) -> Result<Value, ParserError>
         {
-            let bytes = reader.read_bytes(ReadBytesSize::Fixed(self.size)).await?;
-            if let Some(bytes) = bytes {
-                if update_info {
-                    if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
-                        info_provider.add_info(spec_name, Value::U8Vec(bytes.clone()))?;
-                    }
-                }
-                return Ok(ValueType::parse(&self.get_meta_data().value_type, &bytes));
-            } else {
-                Err(ParserError::MissingValue(format!(
-                    "Unable to read {} bytes for placeholder: {:?}",
-                    self.size, self.get_meta_data().get_name().to_name_string()
-                )))
-            }
+            self.inner.deserialize(info_provider, reader, update_info).await
         }
     }
 
     #[async_trait]
-    impl SpecSerialize for NBytesSpec {
+    impl SpecSerialize for ComputedSpec{
         async fn serialize(
             &self,
             info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &mut MapperContext,
             writer: &mut dyn SpecWrite,            
         ) -> Result<(), ParserError>
         {
-            
-            //mapper_context.start_spec(self);
-            let name = self.get_meta_data().get_name().to_name_string();
-            let value = info_provider.get_info_by_spec_path(&mapper_context.get_current_spec_path());
-            write_data(name, value, self.get_meta_data().is_optional(), writer).await?;
-            Ok(())
+            self.inner.serialize(info_provider, mapper_context, writer).await
         }
-    }
 
-    
-    /// Spec to represent bytes of data that is available till the end of stream. e.g request body/payload
-    pub struct AllBytesSpec{     
-        spec_meta_data: SpecMetaData,           
+        /// Computes the value from the source field. Inner spec reports the missing value if the source field is not available
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            let spec_path = mapper_context.get_current_spec_path();
+            let source = with_mapper_context(info_provider, mapper_context, |info_provider| {
+                info_provider.get_info(&self.source_field).cloned()
+            });
+            if let Some(source) = source {
+                let value = self.computer.compute(&source).map_err(|message| ParserError::InvalidValue {
+                    spec_path: spec_path.clone(),
+                    value: format!("{:?}", source),
+                    message,
+                })?;
+                if is_derived_value_missing(&spec_path, info_provider.get_info_by_spec_path(&spec_path), &value)? {
+                    insert_spec_data(info_provider.get_mapper_mut(), spec_path, value);
+                }
+            }
+            self.inner.prepare_serialize(info_provider, mapper_context)
+        }
     }
 
-    impl Spec for AllBytesSpec{
+    /// Marker trait for specs that are not terminated by a delimiter because they know their own size e.g numbers,
+    /// exact strings. Builders use it to add such specs as inline values
+    pub(crate) trait UndelimitedSpec: ProtocolSpec{}
+
+    impl <S> UndelimitedSpec for S where S: NumberSpec{}
+    impl UndelimitedSpec for ExactStringSpec{}
+    impl UndelimitedSpec for LengthFromFieldSpec{}
+    impl UndelimitedSpec for CStringSpec{}
+    impl UndelimitedSpec for QuotedStringSpec{}
+    impl UndelimitedSpec for FixedWidthStringSpec{}
+    impl UndelimitedSpec for NBytesSpec{}
+    impl UndelimitedSpec for ExactBytesSpec{}
+    impl UndelimitedSpec for PaddingSpec{}
+    impl UndelimitedSpec for AllBytesSpec{}
+    impl UndelimitedSpec for EnumMapSpec{}
+    impl UndelimitedSpec for ComputedSpec{}
+    impl UndelimitedSpec for EpochTimestampSpec{}
+    impl UndelimitedSpec for AddressSpec{}
+    impl UndelimitedSpec for RepeatManySpec{}
+
+    /// Marker trait for specs that can be used as key of a key value pair. Bytes keys must be valid utf8
+    pub(crate) trait KeySpec: ProtocolSpec{}
+
+    impl <S> KeySpec for S where S: StringSpec + ProtocolSpec{}
+    impl KeySpec for NBytesSpec{}
+
+    impl Spec for LengthFromFieldSpec{
         fn get_meta_data(&self) -> &SpecMetaData {
             &self.spec_meta_data
         }
     }
 
-    impl SimpleValueSpec for AllBytesSpec{}
+    impl SimpleValueSpec for LengthFromFieldSpec{}
 
     #[async_trait]
-    impl SpecDeserialize for AllBytesSpec{    
+    impl SpecDeserialize for LengthFromFieldSpec{
         async fn deserialize(
             &self,
             info_provider: &mut (dyn InfoProvider + Send + Sync),
-            reader: &mut dyn SpecRead, update_info: bool,   
+            reader: &mut dyn SpecRead, update_info: bool,
         ) -> Result<Value, ParserError>
         {
-            let bytes = reader.read_bytes(ReadBytesSize::Full).await?;
-            if let Some(bytes) = bytes {
-                if update_info{
-                    if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
-                        info_provider.add_info(spec_name, Value::U8Vec(bytes.clone()))?;
-                    }
-                    return Ok(Value::None)
+            let length = match get_deserialized_field(info_provider, &self.length_field, update_info) {
+                Some(value) => self.get_length(value)?,
+                None => {
+                    return Err(ParserError::MissingValue(format!(
+                        "length field {} is missing for placeholder: {:?}",
+                        self.length_field, self.get_meta_data().get_name().to_name_string()
+                    )));
                 }
-                return Ok(ValueType::parse(self.get_meta_data().get_value_type(), &bytes));
+            };
+
+            let bytes = if length == 0 {
+                vec![]
             } else {
-                Err(ParserError::MissingValue(format!(
+                reader.read_bytes(ReadBytesSize::Fixed(length)).await?.ok_or_else(|| ParserError::MissingValue(format!(
                     "Unable to read {} bytes for placeholder: {:?}",
-                    "remaining ", self.get_meta_data().name.to_name_string()
-                )))
+                    length, self.get_meta_data().get_name().to_name_string()
+                )))?
+            };
+
+            let value = match self.get_meta_data().get_value_type() {
+                ValueType::String => Value::String(String::from_utf8(bytes).map_err(|e| ParserError::Utf8Error(e.utf8_error()))?),
+                _ => Value::U8Vec(bytes),
+            };
+            if update_info {
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
+                    info_provider.add_info(spec_name, value)?;
+                }
+                return Ok(Value::None);
             }
+            Ok(value)
         }
     }
 
     #[async_trait]
-    impl SpecSerialize for AllBytesSpec{
+    impl SpecSerialize for LengthFromFieldSpec{
         async fn serialize(
             &self,
             info_provider: &(dyn InfoProvider + Send + Sync), mapper_context: &mut MapperContext,
             writer: &mut dyn SpecWrite,            
         ) -> Result<(), ParserError>
         {
-            let name = self.get_meta_data().get_name().to_name_string();                        
-            //mapper_context.start_spec(self);
+            let name = self.get_meta_data().get_name().to_name_string();
             let value = info_provider.get_info_by_spec_path(&mapper_context.get_current_spec_path());
             write_data(name, value, self.get_meta_data().is_optional(), writer).await?;
             Ok(())
         }
+
+        /// Populates the length field with size of the data. Length field that is already available is verified against the data size
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            let length = match info_provider.get_info_by_spec_path(&mapper_context.get_current_spec_path()) {
+                Some(Value::None) | None => return Ok(()),
+                Some(value) => byte_length(value)?,
+            };
+            let length_value = self.length_encoding.to_value(&self.length_field, length)?;
+            with_mapper_context(info_provider, mapper_context, |info_provider| {
+                if is_derived_value_missing(&self.length_field, info_provider.get_info(&self.length_field), &length_value)? {
+                    info_provider.add_info(self.length_field.clone(), length_value)?;
+                }
+                Ok(())
+            })
+        }
     }
 
     /// Spec to represent string data that matches two or more fixed values e.g request_method in http should have values GET,POST,PUT,DELETE
@@ -2941,6 +6238,8 @@ impl From<serde_json::Error> for ParserError {
         spec_meta_data: SpecMetaData,
         values: Vec<String>,        
         until: Separator,
+        validator: Option<Box<dyn ValueValidator>>,
+        ignore_case: bool,
     }
 
     
@@ -2953,6 +6252,14 @@ impl From<serde_json::Error> for ParserError {
         fn get_delimiter(& self) -> &Separator {
             &self.until
         }
+
+        fn set_validator(&mut self, validator: Box<dyn ValueValidator>) {
+            self.validator = Some(validator);
+        }
+
+        fn get_validator(&self) -> Option<&dyn ValueValidator> {
+            self.validator.as_deref()
+        }
     }
 
     impl Spec for OneOfSpec{
@@ -2967,9 +6274,28 @@ impl From<serde_json::Error> for ParserError {
                 spec_meta_data: SpecMetaData::new(name, ValueType::String, optional),
                 values,
                 until: Separator::EndOfStream,
+                validator: None,
+                ignore_case: false,
             }
         }
 
+        /// Creates one of spec that matches the values ignoring ascii case. Matching value from `values` is stored as the value
+        pub fn new_ignore_case(name: SpecName, optional: bool, values: Vec<String>) -> Self {
+            OneOfSpec {
+                ignore_case: true,
+                ..OneOfSpec::new(name, optional, values)
+            }
+        }
+
+        /// Returns the value from `values` that matches `value`
+        fn find_value(&self, value: &str) -> Option<&String> {
+            self.values.iter().find(|candidate| if self.ignore_case {
+                candidate.eq_ignore_ascii_case(value)
+            } else {
+                *candidate == value
+            })
+        }
+
         pub fn add_value(&mut self, value: String) {
             self.values.push(value);
         }
@@ -2991,11 +6317,12 @@ impl From<serde_json::Error> for ParserError {
             reader: &mut dyn SpecRead, update_info: bool,
         ) -> Result<Value, ParserError>
         {
-            let result = parse_delimited_string_spec(self, reader).await?;
+            let result = parse_delimited_string_spec(self, info_provider, reader).await?;
+            self.validate(&info_provider.get_mapper_context().get_current_spec_path(), &result)?;
             
                 //.undoable_parse(info_provider, reader).await?;
             if let Some(value) = &result.get_string_value() {
-                if self.values.contains(value) {
+                if let Some(value) = self.find_value(value) {
                     if update_info{
                         if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
                             info_provider.add_info(spec_name, Value::String(value.clone()))?;
@@ -3003,7 +6330,7 @@ impl From<serde_json::Error> for ParserError {
                         return Ok(Value::None);
                     }
                     
-                return Ok(result);
+                return Ok(Value::String(value.clone()));
                 } else {
                     return Err(ParserError::MissingValue(format!(
                         "Expected one of {:?}, but got: {:?}",
@@ -3030,9 +6357,13 @@ impl From<serde_json::Error> for ParserError {
         {
             let name = self.get_meta_data().get_name().to_name_string();                        
             //mapper_context.start_spec(self);
-            let value = info_provider.get_info_by_spec_path(&mapper_context.get_current_spec_path());
+            let spec_path = mapper_context.get_current_spec_path();
+            let value = info_provider.get_info_by_spec_path(&spec_path);
+            if let Some(value) = value {
+                self.validate(&spec_path, value)?;
+            }
             write_data(name, value, self.get_meta_data().is_optional(), writer).await?;
-            if let Separator::Delimiter(delimiter) = &self.until{
+            if let Some(delimiter) = self.until.resolve_delimiter(info_provider.get_mapper(), &spec_path){
                 writer.write_all(delimiter.as_bytes()).await?;
             }
             Ok(())
@@ -3057,12 +6388,16 @@ impl From<serde_json::Error> for ParserError {
         ) -> Result<Value, ParserError>
         {
             let mut has_one_success = false;
+            let mut entries = HashMap::new();
             for constituent in &self.constituents {   
                 let result = undoable_deserialize(constituent, info_provider, reader, update_info).await;
                 debug!("deserializing {}", constituent.get_meta_data().get_name());
                 match result{
-                    Ok(_) => {
+                    Ok(value) => {
                         has_one_success = true;
+                        if !update_info {
+                            add_tree_entry(&mut entries, constituent.get_meta_data(), value, info_provider.get_mapper_context())?;
+                        }
                         continue;
                     },
                     Err(ref e) => {
@@ -3080,9 +6415,49 @@ impl From<serde_json::Error> for ParserError {
             if !has_one_success {
                 return Err(ParserError::NoValidListConstituents(self.get_meta_data().get_name().to_path_string()));
             }
+            if !update_info {
+                return Ok(Value::Map(entries));
+            }
             Ok(Value::None) // or some other appropriate return value
         }
     }
+
+    /// Adds value of a constituent to the tree of its composite. Values of named composites and named simple specs are added 
+    /// using the name of the spec, entries of other maps e.g key value pairs, bit fields are added to the composite itself.
+    /// Values of unnamed simple specs e.g delimiters, exact strings are not added. List of an unnamed spec can not be added
+    fn add_tree_entry(entries: &mut HashMap<String, Value>, spec_meta_data: &SpecMetaData, value: Value, mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+        let composite = matches!(spec_meta_data.get_value_type(), ValueType::CompositeMap | ValueType::CompositeList);
+        match (spec_meta_data.get_name(), value) {
+            (_, Value::None) => Ok(()),
+            (SpecName::Name(name), value) if composite || !matches!(value, Value::Map(_)) => {
+                insert_tree_entry(entries, name.clone(), value, mapper_context)
+            },
+            (_, Value::Map(values)) => values.into_iter()
+                .try_for_each(|(name, value)| insert_tree_entry(entries, name, value, mapper_context)),
+            (name, value @ Value::List(_)) => Err(ParserError::InvalidValue {
+                spec_path: name.to_path_string(),
+                value: format!("{:?}", value),
+                message: "list of unnamed spec can not be added to the tree".to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Inserts entry `name` into the tree and records simple values as fields of the tree. Returns error if the entry already exists
+    fn insert_tree_entry(entries: &mut HashMap<String, Value>, name: String, value: Value, mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+        if entries.contains_key(&name) {
+            return Err(ParserError::InvalidValue {
+                spec_path: name,
+                value: format!("{:?}", value),
+                message: "entry already exists in the tree".to_string(),
+            });
+        }
+        if !matches!(value, Value::Map(_) | Value::List(_)) {
+            mapper_context.add_tree_field(name.clone(), value.clone());
+        }
+        entries.insert(name, value);
+        Ok(())
+    }
    
     #[async_trait]
     impl SpecSerialize for ListSpec {
@@ -3110,6 +6485,11 @@ impl From<serde_json::Error> for ParserError {
             //mapper_context.end_spec(self)?;
             Ok(()) // or some other appropriate return value
         }
+
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            self.constituents.iter()
+                .try_for_each(|constituent| prepare(constituent, info_provider, mapper_context))
+        }
     }
 
     impl ListSpec {
@@ -3191,6 +6571,10 @@ impl From<serde_json::Error> for ParserError {
             Ok(())
             
         }
+
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            prepare(&self.0, info_provider, mapper_context)
+        }
     }
 
     impl Default for Box<dyn StringSpec> {
@@ -3258,6 +6642,10 @@ impl From<serde_json::Error> for ParserError {
             serialize(&self.0, info_provider, writer, mapper_context).await?;//.end_spec(mapper_context, self)?;
             Ok(())
         }
+
+        fn prepare_serialize(&self, info_provider: &mut ( dyn InfoProvider + Send + Sync ), mapper_context: &mut MapperContext) -> Result<(), ParserError>{
+            prepare(&self.0, info_provider, mapper_context)
+        }
     }
 
     async fn write_data(name: String, value:Option<&Value>, optional:bool, writer: &mut dyn SpecWrite) -> Result<(), ParserError>{
@@ -3273,21 +6661,61 @@ impl From<serde_json::Error> for ParserError {
 
     /// Represents u64 number spec
     #[derive(Default)]
-    pub struct NumberU64Spec(SpecMetaData) ;
+    pub struct NumberU64Spec(SpecMetaData, Endianness) ;
 
     /// Represents i64 number spec
     #[derive(Default)]
-    pub struct NumberI64Spec(SpecMetaData) ;
+    pub struct NumberI64Spec(SpecMetaData, Endianness) ;
 
     /// Represents u32 number spec
     #[derive(Default)]
-    pub struct NumberU32Spec(SpecMetaData) ;
+    pub struct NumberU32Spec(SpecMetaData, Endianness) ;
 
     /// Represents u16 number spec
     #[derive(Default)]
-    pub struct NumberU16Spec(SpecMetaData) ;
+    pub struct NumberU16Spec(SpecMetaData, Endianness) ;
+
+    /// Represents i16 number spec
+    #[derive(Default)]
+    pub struct NumberI16Spec(SpecMetaData, Endianness);
+
+    /// Represents i32 number spec
+    #[derive(Default)]
+    pub struct NumberI32Spec(SpecMetaData, Endianness);
+
+    /// Represents u8 number spec
+    #[derive(Default)]
+    pub struct NumberU8Spec(SpecMetaData);
+
+    /// Represents i8 number spec
+    #[derive(Default)]
+    pub struct NumberI8Spec(SpecMetaData);
+
+    /// Represents f32 number spec, encoded as IEEE 754 single precision
+    #[derive(Default)]
+    pub struct NumberF32Spec(SpecMetaData, Endianness);
+
+    /// Represents f64 number spec, encoded as IEEE 754 double precision
+    #[derive(Default)]
+    pub struct NumberF64Spec(SpecMetaData, Endianness);
+
+    /// Represents boolean spec encoded as single byte, zero is false and any other value is true
+    #[derive(Default)]
+    pub struct BoolSpec(SpecMetaData);
 
-    pub(crate) trait NumberSpec: SimpleValueSpec + Send + Sync{}
+    /// trait to represent binary number spec which is read and written without delimiters
+    pub(crate) trait NumberSpec: SimpleValueSpec + Send + Sync{
+        /// Encodes the value into bytes written on the wire
+        fn to_bytes(&self, value: &Value) -> Result<Vec<u8>, ParserError>;
+    }
+
+    /// trait to represent fixed size multi byte number spec. Byte order used for reading and writing the number
+    /// is controlled by endianness
+    pub(crate) trait EndianNumberSpec: NumberSpec{
+        fn get_endianness(&self) -> Endianness;
+
+        fn set_endianness(&mut self, endianness: Endianness);
+    }
 
     impl <S> ToSpecType for S where S:SimpleValueSpec{        
     }
@@ -3297,181 +6725,404 @@ impl From<serde_json::Error> for ParserError {
     impl SimpleValueSpec for NumberU16Spec{}
     impl SimpleValueSpec for NumberI16Spec{}
     impl SimpleValueSpec for NumberI64Spec{}
+    impl SimpleValueSpec for NumberI32Spec{}
+    impl SimpleValueSpec for NumberU8Spec{}
+    impl SimpleValueSpec for NumberI8Spec{}
+    impl SimpleValueSpec for NumberF32Spec{}
+    impl SimpleValueSpec for NumberF64Spec{}
+    impl SimpleValueSpec for BoolSpec{}
+
+    /// Converts `value` to the value type declared in spec metadata so that it is written with the spec's width.
+    /// Integers of another width are converted when they fit, floats and booleans must match the declared type
+    fn number_value_of(spec_meta_data: &SpecMetaData, value: &Value) -> Result<Value, ParserError> {
+        let invalid_value = |message: &str| ParserError::InvalidValue {
+            spec_path: spec_meta_data.get_name().to_name_string(),
+            value: format!("{:?}", value),
+            message: message.to_string(),
+        };
+        let integer = match value {
+            Value::UnSignedNumber8(number) => Some(*number as i128),
+            Value::UnSignedNumber16(number) => Some(*number as i128),
+            Value::UnSignedNumber32(number) => Some(*number as i128),
+            Value::UnSignedNumber64(number) => Some(*number as i128),
+            Value::SignedNumber8(number) => Some(*number as i128),
+            Value::SignedNumber16(number) => Some(*number as i128),
+            Value::SignedNumber32(number) => Some(*number as i128),
+            Value::SignedNumber64(number) => Some(*number as i128),
+            _ => None,
+        };
+        let does_not_fit = |_| invalid_value("value does not fit in number spec width");
+        match (spec_meta_data.get_value_type(), integer, value) {
+            (ValueType::UnSignedNumber8, Some(number), _) => u8::try_from(number).map(Value::UnSignedNumber8).map_err(does_not_fit),
+            (ValueType::UnSignedNumber16, Some(number), _) => u16::try_from(number).map(Value::UnSignedNumber16).map_err(does_not_fit),
+            (ValueType::UnSignedNumber32, Some(number), _) => u32::try_from(number).map(Value::UnSignedNumber32).map_err(does_not_fit),
+            (ValueType::UnSignedNumber64, Some(number), _) => u64::try_from(number).map(Value::UnSignedNumber64).map_err(does_not_fit),
+            (ValueType::SignedNumber8, Some(number), _) => i8::try_from(number).map(Value::SignedNumber8).map_err(does_not_fit),
+            (ValueType::SignedNumber16, Some(number), _) => i16::try_from(number).map(Value::SignedNumber16).map_err(does_not_fit),
+            (ValueType::SignedNumber32, Some(number), _) => i32::try_from(number).map(Value::SignedNumber32).map_err(does_not_fit),
+            (ValueType::SignedNumber64, Some(number), _) => i64::try_from(number).map(Value::SignedNumber64).map_err(does_not_fit),
+            (ValueType::Float32, _, Value::Float32(_))
+            | (ValueType::Float64, _, Value::Float64(_))
+            | (ValueType::Bool, _, Value::Bool(_)) => Ok(value.clone()),
+            (ValueType::Float64, _, Value::Float32(number)) => Ok(Value::Float64(*number as f64)),
+            _ => Err(invalid_value("value does not match number spec type")),
+        }
+    }
+
+    macro_rules! impl_number_spec {
+        ($($spec:ty),*) => {
+            $(
+                impl NumberSpec for $spec{
+                    fn to_bytes(&self, value: &Value) -> Result<Vec<u8>, ParserError>{
+                        number_value_of(&self.0, value)?.to_bytes(self.get_endianness())
+                    }
+                }
 
+                impl EndianNumberSpec for $spec{
+                    fn get_endianness(&self) -> Endianness {
+                        self.1
+                    }
 
-    impl NumberSpec for NumberU64Spec{}
-    impl NumberSpec for NumberU32Spec{}
-    impl NumberSpec for NumberU16Spec{}
-    impl NumberSpec for NumberI16Spec{}
-    impl NumberSpec for NumberI64Spec{}
+                    fn set_endianness(&mut self, endianness: Endianness) {
+                        self.1 = endianness;
+                    }
+                }
 
-    impl Spec for NumberU64Spec {
-        fn get_meta_data(&self) -> &SpecMetaData {
-            &self.0
+                impl Spec for $spec {
+                    fn get_meta_data(&self) -> &SpecMetaData {
+                        &self.0
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_number_spec!(NumberU64Spec, NumberU32Spec, NumberU16Spec, NumberI16Spec, NumberI64Spec,
+        NumberI32Spec, NumberF32Spec, NumberF64Spec);
+
+    /// Byte order does not apply to single byte numbers and boolean
+    macro_rules! impl_single_byte_spec {
+        ($($spec:ty),*) => {
+            $(
+                impl NumberSpec for $spec{
+                    fn to_bytes(&self, value: &Value) -> Result<Vec<u8>, ParserError>{
+                        number_value_of(&self.0, value)?.to_bytes(Endianness::Big)
+                    }
+                }
+
+                impl Spec for $spec {
+                    fn get_meta_data(&self) -> &SpecMetaData {
+                        &self.0
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_single_byte_spec!(NumberU8Spec, NumberI8Spec, BoolSpec);
+
+    /// Reads `size` bytes and decodes them as number of value type defined in spec metadata
+    async fn deserialize_number(
+        spec_meta_data: &SpecMetaData,
+        endianness: Endianness,
+        size: u32,
+        info_provider: &mut (dyn InfoProvider + Send + Sync),
+        reader: &mut dyn SpecRead, update_info: bool,
+    ) -> Result<Value, ParserError> {
+        let bytes = reader.read_bytes(ReadBytesSize::Fixed(size)).await?;
+        if let Some(bytes) = bytes {
+            let value = ValueType::parse_with_endianness(spec_meta_data.get_value_type(), &bytes, endianness);
+            if update_info{
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
+                    info_provider.add_info(spec_name, value)?;
+                }
+                Ok(Value::None)
+            }else {
+                Ok(value)
+            }
+        } else {
+            Err(ParserError::MissingValue(format!(
+                "Unable to read {} bytes for placeholder: {:?}",
+                size,
+                spec_meta_data.get_name().to_name_string()
+            )))
+        }
+    }
+    
+    #[async_trait]
+    impl SpecDeserialize for NumberU64Spec {
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool,
+        ) -> Result<Value, ParserError> {
+            deserialize_number(&self.0, self.1, 8, info_provider, reader, update_info).await
         }
     }
 
-    impl Spec for NumberU16Spec {
-        fn get_meta_data(&self) -> &SpecMetaData {
-            &self.0
+    #[async_trait]
+    impl SpecDeserialize for NumberI64Spec {
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool
+        ) -> Result<Value, ParserError> {
+            deserialize_number(&self.0, self.1, 8, info_provider, reader, update_info).await
         }
     }
 
-    impl Spec for NumberU32Spec {
-        fn get_meta_data(&self) -> &SpecMetaData {
-            &self.0
+    #[async_trait]
+    impl SpecDeserialize for NumberU32Spec {
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool
+        ) -> Result<Value, ParserError> {
+            deserialize_number(&self.0, self.1, 4, info_provider, reader, update_info).await
         }
     }
 
-    
-    impl Spec for NumberI16Spec {
-        fn get_meta_data(&self) -> &SpecMetaData {
-            &self.0
+    #[async_trait]
+    impl SpecDeserialize for NumberU16Spec {
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool
+        ) -> Result<Value, ParserError> {
+            deserialize_number(&self.0, self.1, 2, info_provider, reader, update_info).await
         }
     }
 
-    impl Spec for NumberI64Spec {
-        fn get_meta_data(&self) -> &SpecMetaData {
-            &self.0
+    #[async_trait]
+    impl SpecDeserialize for NumberI16Spec {
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool
+        ) -> Result<Value, ParserError> {
+            deserialize_number(&self.0, self.1, 2, info_provider, reader, update_info).await
         }
     }
 
-    
-    #[derive(Default)]
-    pub struct NumberI16Spec(SpecMetaData);
-    
-    
     #[async_trait]
-    impl SpecDeserialize for NumberU64Spec {
+    impl SpecDeserialize for NumberI32Spec {
         async fn deserialize(
             &self,
             info_provider: &mut (dyn InfoProvider + Send + Sync),
-            reader: &mut dyn SpecRead, update_info: bool,
+            reader: &mut dyn SpecRead, update_info: bool
         ) -> Result<Value, ParserError> {
-            let bytes = reader.read_bytes(ReadBytesSize::Fixed(8)).await?;
-            if let Some(bytes) = bytes {
-                if update_info{
-                    if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
-                        info_provider.add_info(spec_name, ValueType::parse(&ValueType::UnSignedNumber64, &bytes))?;
-                    }
-                    return Ok(Value::None);
-                }else {
-                    Ok(ValueType::parse(&ValueType::UnSignedNumber64, &bytes))
-                }
-            } else {
-                Err(ParserError::MissingValue(format!(
-                    "Unable to read 8 bytes for placeholder: {:?}",
-                    self.0.get_name().to_name_string()
-                )))
-            }
+            deserialize_number(&self.0, self.1, 4, info_provider, reader, update_info).await
         }
     }
 
     #[async_trait]
-    impl SpecDeserialize for NumberI64Spec {
+    impl SpecDeserialize for NumberU8Spec {
         async fn deserialize(
             &self,
             info_provider: &mut (dyn InfoProvider + Send + Sync),
             reader: &mut dyn SpecRead, update_info: bool
         ) -> Result<Value, ParserError> {
-            let bytes = reader.read_bytes(ReadBytesSize::Fixed(8)).await?;
-            if let Some(bytes) = bytes {
-                if update_info{
-                    if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
-                        info_provider.add_info(spec_name, ValueType::parse(&ValueType::SignedNumber64, &bytes))?;
-                    }
-                    return Ok(Value::None);
-                }else {
-                    Ok(ValueType::parse(&ValueType::SignedNumber64, &bytes))
-                }
-            } else {
-                Err(ParserError::MissingValue(format!(
-                    "Unable to read 8 bytes for placeholder: {:?}",
-                    self.0.get_name().to_name_string()
-                )))
-            }
+            deserialize_number(&self.0, Endianness::Big, 1, info_provider, reader, update_info).await
         }
     }
 
     #[async_trait]
-    impl SpecDeserialize for NumberU32Spec {
+    impl SpecDeserialize for NumberI8Spec {
         async fn deserialize(
             &self,
             info_provider: &mut (dyn InfoProvider + Send + Sync),
             reader: &mut dyn SpecRead, update_info: bool
         ) -> Result<Value, ParserError> {
-            let bytes = reader.read_bytes(ReadBytesSize::Fixed(4)).await?;
-            if let Some(bytes) = bytes {
-                if update_info{
-                    if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
-                        info_provider.add_info(spec_name, ValueType::parse(&ValueType::UnSignedNumber32, &bytes))?;
-                    }
-                    return Ok(Value::None);
-                }else {
-                    Ok(ValueType::parse(&ValueType::UnSignedNumber32, &bytes))
+            deserialize_number(&self.0, Endianness::Big, 1, info_provider, reader, update_info).await
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for NumberF32Spec {
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool
+        ) -> Result<Value, ParserError> {
+            deserialize_number(&self.0, self.1, 4, info_provider, reader, update_info).await
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for NumberF64Spec {
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool
+        ) -> Result<Value, ParserError> {
+            deserialize_number(&self.0, self.1, 8, info_provider, reader, update_info).await
+        }
+    }
+
+    #[async_trait]
+    impl SpecDeserialize for BoolSpec {
+        async fn deserialize(
+            &self,
+            info_provider: &mut (dyn InfoProvider + Send + Sync),
+            reader: &mut dyn SpecRead, update_info: bool
+        ) -> Result<Value, ParserError> {
+            deserialize_number(&self.0, Endianness::Big, 1, info_provider, reader, update_info).await
+        }
+    }
+
+
+    /// Represents unsigned variable length integer encoded as LEB128, as used by protobuf varints and
+    /// MQTT remaining length. Each byte carries 7 bits of the number, least significant group first,
+    /// and the high bit is set on every byte except the last one
+    #[derive(Default)]
+    pub struct VarIntSpec(SpecMetaData);
+
+    /// Represents signed variable length integer that is zig-zag encoded before LEB128 encoding,
+    /// e.g protobuf sint32/sint64
+    #[derive(Default)]
+    pub struct ZigZagVarIntSpec(SpecMetaData);
+
+    impl SimpleValueSpec for VarIntSpec{}
+    impl SimpleValueSpec for ZigZagVarIntSpec{}
+
+    impl Spec for VarIntSpec {
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.0
+        }
+    }
+
+    impl Spec for ZigZagVarIntSpec {
+        fn get_meta_data(&self) -> &SpecMetaData {
+            &self.0
+        }
+    }
+
+    /// Maximum number of bytes needed to encode u64 as LEB128
+    const MAX_VARINT_SIZE: usize = 10;
+
+    fn encode_varint(mut value: u64) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(MAX_VARINT_SIZE);
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+            if value == 0 {
+                bytes.push(byte);
+                return bytes;
+            }
+            bytes.push(byte | 0x80);
+        }
+    }
+
+    /// Reads LEB128 encoded number one byte at a time
+    async fn read_varint(spec_meta_data: &SpecMetaData, reader: &mut dyn SpecRead) -> Result<u64, ParserError> {
+        let mut value: u64 = 0;
+        for index in 0..MAX_VARINT_SIZE {
+            let byte = match reader.read_bytes(ReadBytesSize::Fixed(1)).await? {
+                Some(bytes) => bytes[0],
+                None => {
+                    return Err(ParserError::MissingValue(format!(
+                        "Unable to read varint byte for placeholder: {:?}",
+                        spec_meta_data.get_name().to_name_string()
+                    )));
                 }
-            } else {
-                Err(ParserError::MissingValue(format!(
-                    "Unable to read 8 bytes for placeholder: {:?}",
-                    self.0.get_name().to_name_string()
-                )))
+            };
+            let group = (byte & 0x7F) as u64;
+            if index == MAX_VARINT_SIZE - 1 && group > 1 {
+                break;
             }
+            value |= group << (7 * index);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(ParserError::InvalidValue {
+            spec_path: spec_meta_data.get_name().to_name_string(),
+            value: format!("{}", value),
+            message: "varint does not fit in 64 bits".to_string(),
+        })
+    }
+
+    fn zigzag_encode(value: i64) -> u64 {
+        ((value << 1) ^ (value >> 63)) as u64
+    }
+
+    fn zigzag_decode(value: u64) -> i64 {
+        ((value >> 1) as i64) ^ -((value & 1) as i64)
+    }
+
+    fn varint_value_error(spec_meta_data: &SpecMetaData, value: &Value) -> ParserError {
+        ParserError::InvalidValue {
+            spec_path: spec_meta_data.get_name().to_name_string(),
+            value: format!("{:?}", value),
+            message: "value cannot be encoded as varint".to_string(),
+        }
+    }
+
+    /// Byte order does not apply to varints, the encoding always starts with least significant group
+    impl NumberSpec for VarIntSpec{
+        fn to_bytes(&self, value: &Value) -> Result<Vec<u8>, ParserError>{
+            let number = match value {
+                Value::UnSignedNumber64(number) => *number,
+                Value::UnSignedNumber32(number) => *number as u64,
+                Value::UnSignedNumber16(number) => *number as u64,
+                Value::UnSignedNumber8(number) => *number as u64,
+                _ => return Err(varint_value_error(&self.0, value)),
+            };
+            Ok(encode_varint(number))
+        }
+    }
+
+    impl NumberSpec for ZigZagVarIntSpec{
+        fn to_bytes(&self, value: &Value) -> Result<Vec<u8>, ParserError>{
+            let number = match value {
+                Value::SignedNumber64(number) => *number,
+                Value::SignedNumber32(number) => *number as i64,
+                Value::SignedNumber16(number) => *number as i64,
+                Value::SignedNumber8(number) => *number as i64,
+                _ => return Err(varint_value_error(&self.0, value)),
+            };
+            Ok(encode_varint(zigzag_encode(number)))
         }
     }
 
     #[async_trait]
-    impl SpecDeserialize for NumberU16Spec {
+    impl SpecDeserialize for VarIntSpec {
         async fn deserialize(
             &self,
             info_provider: &mut (dyn InfoProvider + Send + Sync),
             reader: &mut dyn SpecRead, update_info: bool
         ) -> Result<Value, ParserError> {
-            let bytes = reader.read_bytes(ReadBytesSize::Fixed(4)).await?;
-            if let Some(bytes) = bytes {
-                if update_info{
-                    if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
-                        info_provider.add_info(spec_name, ValueType::parse(&ValueType::UnSignedNumber16, &bytes))?;
-                    }
-                    return Ok(Value::None);
-                }else {
-                    Ok(ValueType::parse(&ValueType::UnSignedNumber16, &bytes))
+            let value = Value::UnSignedNumber64(read_varint(&self.0, reader).await?);
+            if update_info{
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
+                    info_provider.add_info(spec_name, value)?;
                 }
-            } else {
-                Err(ParserError::MissingValue(format!(
-                    "Unable to read 8 bytes for placeholder: {:?}",
-                    self.0.get_name().to_name_string()
-                )))
+                Ok(Value::None)
+            }else {
+                Ok(value)
             }
         }
     }
 
     #[async_trait]
-    impl SpecDeserialize for NumberI16Spec {
+    impl SpecDeserialize for ZigZagVarIntSpec {
         async fn deserialize(
             &self,
             info_provider: &mut (dyn InfoProvider + Send + Sync),
             reader: &mut dyn SpecRead, update_info: bool
         ) -> Result<Value, ParserError> {
-            let bytes = reader.read_bytes(ReadBytesSize::Fixed(4)).await?;
-            if let Some(bytes) = bytes {
-                if update_info{
-                    if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
-                        info_provider.add_info(spec_name, ValueType::parse(&ValueType::SignedNumber16, &bytes))?;
-                    }
-                    return Ok(Value::None);
-                }else {
-                    Ok(ValueType::parse(&ValueType::SignedNumber16, &bytes))
+            let value = Value::SignedNumber64(zigzag_decode(read_varint(&self.0, reader).await?));
+            if update_info{
+                if let Some(spec_name) = info_provider.get_mapper_context().get_last_available_spec_name(){
+                    info_provider.add_info(spec_name, value)?;
                 }
-            } else {
-                Err(ParserError::MissingValue(format!(
-                    "Unable to read 8 bytes for placeholder: {:?}",
-                    self.0.get_name().to_name_string()
-                )))
+                Ok(Value::None)
+            }else {
+                Ok(value)
             }
         }
     }
 
-
     /* #[async_trait]
     impl SpecSerialize for dyn Spec {
 
@@ -3502,8 +7153,14 @@ impl From<serde_json::Error> for ParserError {
             let name = self.get_meta_data().get_name().to_name_string();
             //mapper_context.start_spec(self);
             let value = info_provider.get_info_by_spec_path(&mapper_context.get_current_spec_path());            
-            write_data(name, value, self.get_meta_data().optional, writer).await?;//.end_spec(mapper_context, self)?;
-            Ok(())
+            if let Some(value) = value{
+                writer.write_data_bytes(&self.to_bytes(value)?).await?;
+                Ok(())
+            }else if !self.get_meta_data().optional {
+                Err(ParserError::MissingData(name))
+            }else{
+                Ok(())
+            }
         }
     }
 
@@ -3523,7 +7180,7 @@ impl From<serde_json::Error> for ParserError {
 pub mod builders{
     use std::{marker::PhantomData, mem};
 
-    use crate::core::{DelimitedSpec, DelimitedStringSpec, ExactStringSpec, InlineKeyWithValue, Key, KeyValueSpec, ListSpec, NumberI16Spec, NumberI64Spec, NumberSpec, NumberU16Spec, NumberU32Spec, NumberU64Spec, OneOfSpec, ProtocolSpec, RepeatCount, RepeatManySpec, Separator, Spec, SpecMetaData, SpecName, StringSpec, ValueSpec, ValueType};
+    use crate::core::{Alignment, AllBytesSpec, BitField, BitFieldSpec, BoolSpec, ByteLength, ExactBytesSpec, Padding, PaddingSpec, ChecksumAlgorithm, ChecksumSpec, ComputedSpec, ValueComputer, ValueValidator, CStringSpec, QuotedStringSpec, DelimitedSpec, DelimitedStringSpec, Radix, TextNumberSpec, TimestampFormat, TextTimestampSpec, EpochUnit, EpochTimestampSpec, AddressKind, AddressSpec, TextAddressSpec, Endianness, EnumMapSpec, UnmappedValue, ExactStringSpec, FixedWidthStringSpec, InlineKeyWithValue, Key, KeySpec, KeyValueSpec, LengthEncoding, LengthFromFieldSpec, ListSpec, NBytesSpec, NumberF32Spec, NumberF64Spec, NumberI16Spec, NumberI32Spec, NumberI64Spec, NumberI8Spec, EndianNumberSpec, NumberU16Spec, NumberU32Spec, NumberU64Spec, NumberU8Spec, OneOfSpec, OneOfSpecs, ProtocolSpec, RepeatCount, RepeatManySpec, Separator, Spec, SpecMetaData, SpecName, StringSpec, SwitchSpec, UndelimitedSpec, Value, ValueSpec, ValueType, VarIntSpec, ZigZagVarIntSpec};
 
 
     /// trait represents the current state of the builder
@@ -3675,7 +7332,7 @@ pub mod builders{
             ProtoSpecBuilderData {
                 composite_spec: ListSpec { 
                     spec_meta_data: {
-                        SpecMetaData::new(name, ValueType::None, optional)
+                        SpecMetaData::new(name, ValueType::CompositeMap, optional)
                     },
                     constituents: Vec::new() 
                 },
@@ -3701,19 +7358,43 @@ pub mod builders{
     ///Creates various types of number spec e.g NumberU16Spec, NumberI16Spec
     pub trait NumberSpecGenerator {
         fn get_u16_spec(&self, name: SpecName, optional: bool) -> NumberU16Spec{
-            NumberU16Spec(SpecMetaData::new(name, ValueType::UnSignedNumber16, optional))       
+            NumberU16Spec(SpecMetaData::new(name, ValueType::UnSignedNumber16, optional), Endianness::Big)       
         }
         fn get_u32_spec(&self, name: SpecName, optional: bool) -> NumberU32Spec{
-            NumberU32Spec(SpecMetaData::new(name, ValueType::UnSignedNumber32, optional))       
+            NumberU32Spec(SpecMetaData::new(name, ValueType::UnSignedNumber32, optional), Endianness::Big)       
         }
         fn get_u64_spec(&self, name: SpecName, optional: bool) -> NumberU64Spec{
-            NumberU64Spec(SpecMetaData::new(name, ValueType::UnSignedNumber64, optional))       
+            NumberU64Spec(SpecMetaData::new(name, ValueType::UnSignedNumber64, optional), Endianness::Big)       
         }
         fn get_i16_spec(&self, name: SpecName, optional: bool) -> NumberI16Spec{
-            NumberI16Spec(SpecMetaData::new(name, ValueType::SignedNumber16, optional))       
+            NumberI16Spec(SpecMetaData::new(name, ValueType::SignedNumber16, optional), Endianness::Big)       
         }
         fn get_i64_spec(&self, name: SpecName, optional: bool) -> NumberI64Spec{
-            NumberI64Spec(SpecMetaData::new(name, ValueType::SignedNumber64, optional))
+            NumberI64Spec(SpecMetaData::new(name, ValueType::SignedNumber64, optional), Endianness::Big)
+        }
+        fn get_i32_spec(&self, name: SpecName, optional: bool) -> NumberI32Spec{
+            NumberI32Spec(SpecMetaData::new(name, ValueType::SignedNumber32, optional), Endianness::Big)
+        }
+        fn get_u8_spec(&self, name: SpecName, optional: bool) -> NumberU8Spec{
+            NumberU8Spec(SpecMetaData::new(name, ValueType::UnSignedNumber8, optional))
+        }
+        fn get_i8_spec(&self, name: SpecName, optional: bool) -> NumberI8Spec{
+            NumberI8Spec(SpecMetaData::new(name, ValueType::SignedNumber8, optional))
+        }
+        fn get_f32_spec(&self, name: SpecName, optional: bool) -> NumberF32Spec{
+            NumberF32Spec(SpecMetaData::new(name, ValueType::Float32, optional), Endianness::Big)
+        }
+        fn get_f64_spec(&self, name: SpecName, optional: bool) -> NumberF64Spec{
+            NumberF64Spec(SpecMetaData::new(name, ValueType::Float64, optional), Endianness::Big)
+        }
+        fn get_bool_spec(&self, name: SpecName, optional: bool) -> BoolSpec{
+            BoolSpec(SpecMetaData::new(name, ValueType::Bool, optional))
+        }
+        fn get_varint_spec(&self, name: SpecName, optional: bool) -> VarIntSpec{
+            VarIntSpec(SpecMetaData::new(name, ValueType::UnSignedNumber64, optional))
+        }
+        fn get_zigzag_varint_spec(&self, name: SpecName, optional: bool) -> ZigZagVarIntSpec{
+            ZigZagVarIntSpec(SpecMetaData::new(name, ValueType::SignedNumber64, optional))
         }
     }
 
@@ -3722,7 +7403,8 @@ pub mod builders{
         fn get_string_spec(&self, name: SpecName, optional: bool) -> DelimitedStringSpec where  Self:Sized{
             DelimitedStringSpec { 
                 spec_meta_data: SpecMetaData::new(name, ValueType::String, optional), 
-                until: Separator::EndOfStream 
+                until: Separator::EndOfStream,
+                validator: None,
             }
         }
     }
@@ -3802,119 +7484,523 @@ pub mod builders{
             self.wrap_with_data(spec).into() 
         }
 
-        fn expect_i64(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
-        where 
-        OBS: BuilderState +  'static,
-        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberI64Spec, IBS>> + 'static,            
+        fn expect_i64(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberI64Spec, IBS>> + 'static,            
+        {
+            let spec = self.get_i64_spec(name, optional);
+            self.wrap_with_data(spec).into()
+        }
+
+        /// Same as `expect_u16` but the number is read and written in little-endian byte order
+        fn expect_u16_le(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberU16Spec, IBS>> + 'static,            
+        {
+            let mut spec = self.get_u16_spec(name, optional);
+            spec.set_endianness(Endianness::Little);
+            self.wrap_with_data(spec).into()
+        }
+
+        /// Same as `expect_u32` but the number is read and written in little-endian byte order
+        fn expect_u32_le(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberU32Spec, IBS>> + 'static,            
+        {
+            let mut spec = self.get_u32_spec(name, optional);
+            spec.set_endianness(Endianness::Little);
+            self.wrap_with_data(spec).into()
+        }
+
+        /// Same as `expect_u64` but the number is read and written in little-endian byte order
+        fn expect_u64_le(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberU64Spec, IBS>> + 'static,            
+        {
+            let mut spec = self.get_u64_spec(name, optional);
+            spec.set_endianness(Endianness::Little);
+            self.wrap_with_data(spec).into()
+        }
+
+        /// Same as `expect_i16` but the number is read and written in little-endian byte order
+        fn expect_i16_le(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberI16Spec, IBS>> + 'static,            
+        {
+            let mut spec = self.get_i16_spec(name, optional);
+            spec.set_endianness(Endianness::Little);
+            self.wrap_with_data(spec).into()
+        }
+
+        /// Same as `expect_i64` but the number is read and written in little-endian byte order
+        fn expect_i64_le(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberI64Spec, IBS>> + 'static,            
+        {
+            let mut spec = self.get_i64_spec(name, optional);
+            spec.set_endianness(Endianness::Little);
+            self.wrap_with_data(spec).into()
+        }
+
+        /// Same as `expect_i32` but the number is read and written in little-endian byte order
+        fn expect_i32_le(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberI32Spec, IBS>> + 'static,            
+        {
+            let mut spec = self.get_i32_spec(name, optional);
+            spec.set_endianness(Endianness::Little);
+            self.wrap_with_data(spec).into()
+        }
+
+        /// Same as `expect_f32` but the number is read and written in little-endian byte order
+        fn expect_f32_le(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberF32Spec, IBS>> + 'static,            
+        {
+            let mut spec = self.get_f32_spec(name, optional);
+            spec.set_endianness(Endianness::Little);
+            self.wrap_with_data(spec).into()
+        }
+
+        /// Same as `expect_f64` but the number is read and written in little-endian byte order
+        fn expect_f64_le(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberF64Spec, IBS>> + 'static,            
+        {
+            let mut spec = self.get_f64_spec(name, optional);
+            spec.set_endianness(Endianness::Little);
+            self.wrap_with_data(spec).into()
+        }
+
+        fn expect_i32(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberI32Spec, IBS>> + 'static,            
+        {
+            let spec = self.get_i32_spec(name, optional);
+            self.wrap_with_data(spec).into()
+        }
+
+        fn expect_u8(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberU8Spec, IBS>> + 'static,            
+        {
+            let spec = self.get_u8_spec(name, optional);
+            self.wrap_with_data(spec).into()
+        }
+
+        fn expect_i8(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberI8Spec, IBS>> + 'static,            
+        {
+            let spec = self.get_i8_spec(name, optional);
+            self.wrap_with_data(spec).into()
+        }
+
+        fn expect_f32(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberF32Spec, IBS>> + 'static,            
+        {
+            let spec = self.get_f32_spec(name, optional);
+            self.wrap_with_data(spec).into()
+        }
+
+        fn expect_f64(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NumberF64Spec, IBS>> + 'static,            
+        {
+            let spec = self.get_f64_spec(name, optional);
+            self.wrap_with_data(spec).into()
+        }
+
+        fn expect_bool(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, BoolSpec, IBS>> + 'static,            
+        {
+            let spec = self.get_bool_spec(name, optional);
+            self.wrap_with_data(spec).into()
+        }
+
+        /// Expects unsigned LEB128 varint, value is stored as u64
+        fn expect_varint(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, VarIntSpec, IBS>> + 'static,            
+        {
+            let spec = self.get_varint_spec(name, optional);
+            self.wrap_with_data(spec).into()
+        }
+
+        /// Expects zig-zag encoded signed LEB128 varint, value is stored as i64
+        fn expect_zigzag_varint(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where 
+        OBS: BuilderState +  'static,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, ZigZagVarIntSpec, IBS>> + 'static,            
+        {
+            let spec = self.get_zigzag_varint_spec(name, optional);
+            self.wrap_with_data(spec).into()
+        }
+    }
+
+    pub trait InlineValueBuilder <IBS, OBS> :StringSpecGenerator + ProtoSpecBuilder<IBS>  
+    where 
+        Self: Sized + 'static,
+        IBS: BuilderState + 'static,
+        OBS:BuilderState + 'static, 
+    {
+
+        fn inline_value_follows(self, key_name: SpecName, optional: bool) ->  ProtoSpecBuilderData<OBS>//impl ProtoSpecBuilder<BuildDelimiter<DelimitedStringSpec, IBS>> 
+        where                        
+        ProtoSpecBuilderData<OBS>:ProtoSpecBuilder<BuildInlineValue> + From<BuilderWrapperWithData<Self, BuildInlineValue, IBS>>  + 'static,            
+
+        {
+            self.wrap_with_data(BuildInlineValue{                
+                value_spec_metadata:SpecMetaData { name:  key_name, value_type: ValueType::None, optional }
+            }).into()
+        }
+    }
+    
+    impl From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildFromScratch>, BuildInlineValue, BuildFromScratch>> for ProtoSpecBuilderData<BuildInlineValue>{
+        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildFromScratch>, BuildInlineValue, BuildFromScratch>) -> Self {
+            let from_builder = value.0;
+            //let from_state = from_builder.replace_current_state_with_default();
+            let inline_value_data  = value.1;
+            let mut to_builder = ProtoSpecBuilderData::default();
+            to_builder.set_state(inline_value_data);
+            to_builder.set_spec(from_builder.composite_spec);
+            to_builder
+        }
+    }
+
+    pub trait ValueBuilder <IBS> : ProtoSpecBuilder<IBS>  
+    where 
+        Self: Sized + 'static,
+        IBS: BuilderState + 'static,
+        
+    {
+
+        fn value_follows(self, name: SpecName, optional: bool) ->  ProtoSpecBuilderData<BuildValue>
+        where ProtoSpecBuilderData<BuildValue>: From<BuilderWrapperWithData<Self, SpecMetaData, IBS>>
+        
+
+        {
+            self.wrap_with_data(SpecMetaData::new(name, ValueType::None, optional)).into()
+        }
+    }
+
+    impl ValueBuilder<BuildKeyAvailable> for ProtoSpecBuilderData<BuildKeyAvailable>{}
+
+    impl  InlineValueBuilder<BuildFromScratch, BuildInlineValue> for ProtoSpecBuilderData<BuildFromScratch>{}
+
+    
+
+
+    /// Trait that allows to adding ListSpec to Spec Builder
+    pub trait CompositeBuilder<IBS, OBS>: ProtoSpecBuilder<IBS>
+    where 
+        IBS: BuilderState + 'static,
+        OBS: BuilderState + 'static,
+        Self: Sized + 'static
+    {
+
+        fn expect_composite(self, spec: ListSpec) -> ProtoSpecBuilderData<OBS>
+        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, ListSpec, IBS>>,
+        {
+            //self.add_spec(Box::new(spec));
+            self.wrap_with_data(spec).into()
+        }
+
+        /// Expects one of the `alternatives` tried in the given order. Name of the matched alternative is recorded as value of `name`
+        fn expect_one_of_specs(self, name: SpecName, optional: bool, alternatives: Vec<ListSpec>) -> ProtoSpecBuilderData<OBS>
+        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, OneOfSpecs, IBS>>,
+        {
+            self.wrap_with_data(OneOfSpecs::new(name, alternatives, optional)).into()
+        }
+
+        /// Expects a u8, u16 or u32 word of type `word_type` that is split into `fields` from the most significant bit
+        fn expect_bit_fields(self, name: SpecName, optional: bool, word_type: ValueType, endianness: Endianness, fields: Vec<BitField>) -> ProtoSpecBuilderData<OBS>
+        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, BitFieldSpec, IBS>>,
+        {
+            self.wrap_with_data(BitFieldSpec::new(name, word_type, endianness, fields, optional)).into()
+        }
+
+        /// Expects `covered` constituents followed by their checksum computed using `algorithm`
+        fn expect_checksum(self, name: SpecName, optional: bool, covered: ListSpec, algorithm: ChecksumAlgorithm, endianness: Endianness) -> ProtoSpecBuilderData<OBS>
+        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, ChecksumSpec, IBS>>,
+        {
+            self.wrap_with_data(ChecksumSpec::new(name, covered, algorithm, endianness, optional)).into()
+        }
+    }
+
+    impl CompositeBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch> {}
+
+    /// Trait that allows adding SwitchSpec to Spec Builder
+    pub trait SwitchBuilder<IBS, OBS>: ProtoSpecBuilder<IBS>
+    where 
+        IBS: BuilderState + 'static,
+        OBS: BuilderState + 'static,
+        Self: Sized + 'static
+    {
+
+        /// Expects one of the `cases` branches selected by the value of previously parsed field `discriminator_field`.
+        /// `default_case` is used when no case value matches the discriminator
+        fn expect_switch(self, name: SpecName, optional: bool, discriminator_field: String, cases: Vec<(Value, ListSpec)>, default_case: Option<ListSpec>) -> ProtoSpecBuilderData<OBS>
+        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, SwitchSpec, IBS>>,
+        {
+            let switch_spec = SwitchSpec::new(name, discriminator_field, cases, default_case, optional);
+            self.wrap_with_data(switch_spec).into()
+        }
+    }
+
+    impl SwitchBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch> {}
+
+    pub trait RepeatBuilder<IBS, OBS>: ProtoSpecBuilder<IBS>
+    where 
+        IBS: BuilderState + 'static,
+        OBS: BuilderState + 'static,
+        Self: Sized + 'static
+    {
+
+        /// Repeats `spec` till the `separator` is found
+        ///
+        /// # Panics
+        /// Panics if `separator` is `Separator::AnyOf` without delimiters
+        fn repeat_many(self, name: SpecName, optional: bool, separator: Separator, spec: ListSpec) -> ProtoSpecBuilderData<OBS>
+        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, RepeatManySpec, IBS>>,
+        {
+            assert!(!matches!(&separator, Separator::AnyOf(delimiters) if delimiters.is_empty()), "at least one delimiter is expected");
+            let repeat_spec = RepeatManySpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::CompositeList, optional),
+                constituents: spec,
+                repeat_count: RepeatCount::Delimited(separator),
+                
+            };
+            self.wrap_with_data(repeat_spec).into()
+        }
+
+        fn repeat_n_times(self, name: SpecName, optional: bool, number_of_times: u32, spec: ListSpec) -> ProtoSpecBuilderData<OBS>
+        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, RepeatManySpec, IBS>>,
+        {
+            let repeat_spec = RepeatManySpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::CompositeList, optional),
+                constituents: spec,
+                repeat_count: RepeatCount::Fixed(number_of_times),
+            };
+            self.wrap_with_data(repeat_spec).into()
+        }
+
+        /// Repeats `spec` as many times as the value of previously parsed field `count_field`
+        fn repeat_with_count_from(self, name: SpecName, optional: bool, count_field: String, count_encoding: LengthEncoding, spec: ListSpec) -> ProtoSpecBuilderData<OBS>
+        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, RepeatManySpec, IBS>>,
         {
-            let spec = self.get_i64_spec(name, optional);
-            self.wrap_with_data(spec).into()
+            let repeat_spec = RepeatManySpec{
+                spec_meta_data: SpecMetaData::new(name, ValueType::CompositeList, optional),
+                constituents: spec,
+                repeat_count: RepeatCount::FromField(count_field, count_encoding),
+            };
+            self.wrap_with_data(repeat_spec).into()
         }
     }
 
-    pub trait InlineValueBuilder <IBS, OBS> :StringSpecGenerator + ProtoSpecBuilder<IBS>  
+    impl RepeatBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch>{}
+
+    impl RepeatBuilder<BuildInlineValue, BuildFromScratch> for ProtoSpecBuilderData<BuildInlineValue>{}
+
+    impl RepeatBuilder<BuildValue, BuildFromScratch> for ProtoSpecBuilderData<BuildValue>{}
+
+    /// Trait that allows adding string or bytes whose length is available in a previously parsed field
+    pub trait LengthFromFieldSpecBuilder <IBS, OBS> : ProtoSpecBuilder<IBS>  
     where 
         Self: Sized + 'static,
         IBS: BuilderState + 'static,
         OBS:BuilderState + 'static, 
     {
 
-        fn inline_value_follows(self, key_name: SpecName, optional: bool) ->  ProtoSpecBuilderData<OBS>//impl ProtoSpecBuilder<BuildDelimiter<DelimitedStringSpec, IBS>> 
-        where                        
-        ProtoSpecBuilderData<OBS>:ProtoSpecBuilder<BuildInlineValue> + From<BuilderWrapperWithData<Self, BuildInlineValue, IBS>>  + 'static,            
-
+        fn expect_string_with_length_from(self, name: SpecName, length_field: String, length_encoding: LengthEncoding, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, LengthFromFieldSpec, IBS>> + 'static,
         {
-            self.wrap_with_data(BuildInlineValue{                
-                value_spec_metadata:SpecMetaData { name:  key_name, value_type: ValueType::None, optional }
-            }).into()
+            let spec = LengthFromFieldSpec::new_string(name, length_field, length_encoding, optional);
+            self.wrap_with_data(spec).into()
         }
-    }
-    
-    impl From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildFromScratch>, BuildInlineValue, BuildFromScratch>> for ProtoSpecBuilderData<BuildInlineValue>{
-        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildFromScratch>, BuildInlineValue, BuildFromScratch>) -> Self {
-            let from_builder = value.0;
-            //let from_state = from_builder.replace_current_state_with_default();
-            let inline_value_data  = value.1;
-            let mut to_builder = ProtoSpecBuilderData::default();
-            to_builder.set_state(inline_value_data);
-            to_builder.set_spec(from_builder.composite_spec);
-            to_builder
+
+        fn expect_bytes_with_length_from(self, name: SpecName, length_field: String, length_encoding: LengthEncoding, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, LengthFromFieldSpec, IBS>> + 'static,
+        {
+            let spec = LengthFromFieldSpec::new_bytes(name, length_field, length_encoding, optional);
+            self.wrap_with_data(spec).into()
         }
     }
 
-    pub trait ValueBuilder <IBS> : ProtoSpecBuilder<IBS>  
+    impl LengthFromFieldSpecBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch>{}
+
+    impl LengthFromFieldSpecBuilder<BuildInlineValue, BuildFromScratch> for ProtoSpecBuilderData<BuildInlineValue>{}
+
+    impl LengthFromFieldSpecBuilder<BuildValue, BuildFromScratch> for ProtoSpecBuilderData<BuildValue>{}
+
+    /// Trait that allows adding fixed size bytes or bytes till the end of stream
+    pub trait BytesSpecBuilder <IBS, OBS> : ProtoSpecBuilder<IBS>  
     where 
         Self: Sized + 'static,
         IBS: BuilderState + 'static,
-        
+        OBS:BuilderState + 'static, 
     {
 
-        fn value_follows(self, name: SpecName, optional: bool) ->  ProtoSpecBuilderData<BuildValue>
-        where ProtoSpecBuilderData<BuildValue>: From<BuilderWrapperWithData<Self, SpecMetaData, IBS>>
-        
+        fn expect_bytes(self, name: SpecName, size: u32, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, NBytesSpec, IBS>> + 'static,
+        {
+            self.wrap_with_data(NBytesSpec::new(name, size, optional)).into()
+        }
 
+        /// Expects all the bytes till the end of stream e.g payload of a message framed by the transport
+        fn expect_remaining_bytes(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, AllBytesSpec, IBS>> + 'static,
         {
-            self.wrap_with_data(SpecMetaData::new(name, ValueType::None, optional)).into()
+            self.wrap_with_data(AllBytesSpec::new(name, optional)).into()
+        }
+
+        /// Expects the constant `magic` bytes e.g file signature of binary formats
+        fn expect_magic(self, magic: &[u8]) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, ExactBytesSpec, IBS>> + 'static,
+        {
+            self.wrap_with_data(ExactBytesSpec::new(SpecName::Transient("magic".to_owned()), magic.to_vec(), false)).into()
+        }
+
+        /// Expects padding bytes till the offset is a multiple of `alignment`
+        fn align_to(self, alignment: u32) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, PaddingSpec, IBS>> + 'static,
+        {
+            self.wrap_with_data(PaddingSpec::new(SpecName::Transient("padding".to_owned()), Padding::AlignTo(alignment), false)).into()
+        }
+
+        /// Expects `size` padding bytes
+        fn padding(self, size: u32) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, PaddingSpec, IBS>> + 'static,
+        {
+            self.wrap_with_data(PaddingSpec::new(SpecName::Transient("padding".to_owned()), Padding::Fixed(size), false)).into()
         }
     }
 
-    impl ValueBuilder<BuildKeyAvailable> for ProtoSpecBuilderData<BuildKeyAvailable>{}
+    impl BytesSpecBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch>{}
 
-    impl  InlineValueBuilder<BuildFromScratch, BuildInlineValue> for ProtoSpecBuilderData<BuildFromScratch>{}
+    impl BytesSpecBuilder<BuildInlineValue, BuildFromScratch> for ProtoSpecBuilderData<BuildInlineValue>{}
 
-    
+    impl BytesSpecBuilder<BuildValue, BuildFromScratch> for ProtoSpecBuilderData<BuildValue>{}
 
+    impl BytesSpecBuilder<BuildKey, BuildKeyAvailable> for ProtoSpecBuilderData<BuildKey>{}
 
-    /// Trait that allows to adding ListSpec to Spec Builder
-    pub trait CompositeBuilder<IBS, OBS>: ProtoSpecBuilder<IBS>
+    /// Trait that allows adding values that are mapped to symbolic names using a table
+    pub trait EnumMapBuilder <IBS, OBS> : NumberSpecGenerator + ProtoSpecBuilder<IBS>  
     where 
+        Self: Sized + 'static,
         IBS: BuilderState + 'static,
-        OBS: BuilderState + 'static,
-        Self: Sized + 'static
+        OBS:BuilderState + 'static, 
     {
 
-        fn expect_composite(self, spec: ListSpec) -> ProtoSpecBuilderData<OBS>
-        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, ListSpec, IBS>>,
+        /// Expects value read by `inner` spec that is mapped to name using `table`
+        fn expect_enum_map(self, name: SpecName, optional: bool, inner: Box<dyn ProtocolSpec>, table: Vec<(Value, String)>, unmapped: UnmappedValue) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, EnumMapSpec, IBS>> + 'static,
         {
-            //self.add_spec(Box::new(spec));
-            self.wrap_with_data(spec).into()
+            self.wrap_with_data(EnumMapSpec::new(name, inner, table, unmapped, optional)).into()
+        }
+
+        /// Expects u8 code that is mapped to name using `table`
+        fn expect_u8_enum(self, name: SpecName, optional: bool, table: Vec<(u8, String)>, unmapped: UnmappedValue) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, EnumMapSpec, IBS>> + 'static,
+        {
+            let inner = Box::new(self.get_u8_spec(SpecName::NoName, optional));
+            let table = table.into_iter().map(|(code, name)| (Value::UnSignedNumber8(code), name)).collect();
+            self.expect_enum_map(name, optional, inner, table, unmapped)
+        }
+
+        /// Expects big-endian u16 code that is mapped to name using `table`
+        fn expect_u16_enum(self, name: SpecName, optional: bool, table: Vec<(u16, String)>, unmapped: UnmappedValue) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, EnumMapSpec, IBS>> + 'static,
+        {
+            let inner = Box::new(self.get_u16_spec(SpecName::NoName, optional));
+            let table = table.into_iter().map(|(code, name)| (Value::UnSignedNumber16(code), name)).collect();
+            self.expect_enum_map(name, optional, inner, table, unmapped)
         }
     }
 
-    impl CompositeBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch> {}
+    impl EnumMapBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch>{}
 
-    pub trait RepeatBuilder<IBS, OBS>: ProtoSpecBuilder<IBS>
+    impl EnumMapBuilder<BuildInlineValue, BuildFromScratch> for ProtoSpecBuilderData<BuildInlineValue>{}
+
+    impl EnumMapBuilder<BuildValue, BuildFromScratch> for ProtoSpecBuilderData<BuildValue>{}
+
+    /// Trait that allows adding fields whose value is computed from another field during serialization
+    pub trait ComputedSpecBuilder <IBS, OBS> : NumberSpecGenerator + ProtoSpecBuilder<IBS>  
     where 
+        Self: Sized + 'static,
         IBS: BuilderState + 'static,
-        OBS: BuilderState + 'static,
-        Self: Sized + 'static
+        OBS:BuilderState + 'static, 
     {
 
-        fn repeat_many(self, name: SpecName, optional: bool, separator: Separator, spec: ListSpec) -> ProtoSpecBuilderData<OBS>
-        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, RepeatManySpec, IBS>>,
+        /// Expects value read by `inner` spec. The value is computed from `source_field` using `computer` during serialization
+        fn expect_computed(self, name: SpecName, optional: bool, inner: Box<dyn ProtocolSpec>, source_field: String, computer: Box<dyn ValueComputer>) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, ComputedSpec, IBS>> + 'static,
         {
-            let repeat_spec = RepeatManySpec{
-                spec_meta_data: SpecMetaData::new(name, ValueType::None, optional),
-                constituents: spec,
-                repeat_count: RepeatCount::Delimited(separator),
-                
-            };
-            self.wrap_with_data(repeat_spec).into()
+            self.wrap_with_data(ComputedSpec::new(name, inner, source_field, computer, optional)).into()
         }
 
-        fn repeat_n_times(self, name: SpecName, optional: bool, number_of_times: u32, spec: ListSpec) -> ProtoSpecBuilderData<OBS>
-        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, RepeatManySpec, IBS>>,
+        /// Expects u8 length of `source_field` e.g length prefix of a short string
+        fn expect_u8_length_of(self, name: SpecName, optional: bool, source_field: String) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, ComputedSpec, IBS>> + 'static,
         {
-            let repeat_spec = RepeatManySpec{
-                spec_meta_data: SpecMetaData::new(name, ValueType::None, optional),
-                constituents: spec,
-                repeat_count: RepeatCount::Fixed(number_of_times),
-            };
-            self.wrap_with_data(repeat_spec).into()
+            let inner = Box::new(self.get_u8_spec(SpecName::NoName, optional));
+            self.expect_computed(name, optional, inner, source_field, Box::new(ByteLength(LengthEncoding::Binary(ValueType::UnSignedNumber8))))
+        }
+
+        /// Expects big-endian u16 length of `source_field`
+        fn expect_u16_length_of(self, name: SpecName, optional: bool, source_field: String) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, ComputedSpec, IBS>> + 'static,
+        {
+            let inner = Box::new(self.get_u16_spec(SpecName::NoName, optional));
+            self.expect_computed(name, optional, inner, source_field, Box::new(ByteLength(LengthEncoding::Binary(ValueType::UnSignedNumber16))))
+        }
+
+        /// Expects big-endian u32 length of `source_field`
+        fn expect_u32_length_of(self, name: SpecName, optional: bool, source_field: String) -> ProtoSpecBuilderData<OBS> 
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, ComputedSpec, IBS>> + 'static,
+        {
+            let inner = Box::new(self.get_u32_spec(SpecName::NoName, optional));
+            self.expect_computed(name, optional, inner, source_field, Box::new(ByteLength(LengthEncoding::Binary(ValueType::UnSignedNumber32))))
         }
     }
 
-    impl RepeatBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch>{}
+    impl ComputedSpecBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch>{}
+
+    impl ComputedSpecBuilder<BuildInlineValue, BuildFromScratch> for ProtoSpecBuilderData<BuildInlineValue>{}
+
+    impl ComputedSpecBuilder<BuildValue, BuildFromScratch> for ProtoSpecBuilderData<BuildValue>{}
 
     /// Trait that allows to adding delimited string spec to Spec Builder
     pub trait DelimitedStringSpecBuilder <IBS> :StringSpecGenerator + ProtoSpecBuilder<IBS>  
@@ -3943,6 +8029,15 @@ pub mod builders{
             self.wrap_with_data(one_of_spec).into()            
         }
 
+        /// Expects one of the `options` ignoring ascii case. Matching option is stored as the value
+        fn expect_one_of_string_ignore_case(self, name: SpecName, optional: bool, options: Vec<String>) ->  ProtoSpecBuilderData<BuildDelimiter<OneOfSpec, IBS>>
+        where
+        ProtoSpecBuilderData<BuildDelimiter<OneOfSpec, IBS>>:From<BuilderWrapperWithData<Self, OneOfSpec, IBS>> + 'static
+        {
+            let one_of_spec = OneOfSpec::new_ignore_case(name, optional, options);
+            self.wrap_with_data(one_of_spec).into()            
+        }
+
     }
     
     /// Trait that allows to adding known string spec to Spec Builder
@@ -3964,6 +8059,17 @@ pub mod builders{
             self.wrap_with_data(exact_string).into()
         }
 
+        /// Expects `input` ignoring ascii case e.g SMTP verbs. `input` is stored as the value when matched
+        fn expect_exact_string_ignore_case(self, name: SpecName, input: String, optional: bool) -> ProtoSpecBuilderData<OBS> 
+        where
+            Self: Sized + 'static,
+            OBS: BuilderState +  'static,
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, ExactStringSpec, IBS>> + 'static,            
+        {
+            let exact_string = ExactStringSpec::new_ignore_case(name, input, optional);
+            self.wrap_with_data(exact_string).into()
+        }
+
         fn expect_newline(self) -> ProtoSpecBuilderData<OBS> 
         where
             Self: Sized + 'static,
@@ -3981,6 +8087,39 @@ pub mod builders{
             ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, ExactStringSpec, IBS>> + 'static,{
                 self.expect_exact_string(SpecName::Delimiter, " ".to_string(), false)
             }
+
+        /// Expects string terminated by NUL byte
+        fn expect_cstring(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS>
+        where
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, CStringSpec, IBS>> + 'static
+        {
+            self.wrap_with_data(CStringSpec::new(name, optional)).into()
+        }
+
+        /// Expects string enclosed in double quotes where `"` and `\` inside the string are escaped with `\`
+        fn expect_quoted_string(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS>
+        where
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, QuotedStringSpec, IBS>> + 'static
+        {
+            self.wrap_with_data(QuotedStringSpec::new(name, b'"', b'\\', optional)).into()
+        }
+
+        /// Expects string enclosed in `quote` where `quote` and `escape` inside the string are escaped with `escape`
+        fn expect_quoted_string_with(self, name: SpecName, quote: u8, escape: u8, optional: bool) -> ProtoSpecBuilderData<OBS>
+        where
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, QuotedStringSpec, IBS>> + 'static
+        {
+            self.wrap_with_data(QuotedStringSpec::new(name, quote, escape, optional)).into()
+        }
+
+        /// Expects string of `width` bytes padded with `pad_byte`. Padding is trimmed on read and added on write based on `alignment`.
+        /// Field made only of the pad byte is read as empty string
+        fn expect_fixed_width_string(self, name: SpecName, width: u32, pad_byte: u8, alignment: Alignment, optional: bool) -> ProtoSpecBuilderData<OBS>
+        where
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, FixedWidthStringSpec, IBS>> + 'static
+        {
+            self.wrap_with_data(FixedWidthStringSpec::new(name, width, pad_byte, alignment, optional)).into()
+        }
     }
 
    
@@ -4039,6 +8178,120 @@ pub mod builders{
     {       
     }
 
+    /// Trait that allows adding integers written as text digits terminated by a delimiter
+    pub trait TextNumberSpecBuilder <IBS, OBS> : ProtoSpecBuilder<IBS>  
+    where 
+        Self: Sized + 'static,
+        IBS: BuilderState + 'static,
+        OBS:BuilderState + 'static, 
+    {
+        /// Expects ascii decimal digits parsed into integer of `value_type` e.g ValueType::UnSignedNumber64
+        fn expect_decimal(self, name: SpecName, value_type: ValueType, optional: bool) ->  ProtoSpecBuilderData<BuildDelimiter<TextNumberSpec, IBS>>
+        where
+        ProtoSpecBuilderData<BuildDelimiter<TextNumberSpec, IBS>>:From<BuilderWrapperWithData<Self, TextNumberSpec, IBS>> + 'static,
+        {
+            self.wrap_with_data(TextNumberSpec::new(name, value_type, Radix::Decimal, optional)).into()
+        }
+
+        /// Expects ascii hex digits parsed into integer of `value_type` e.g chunk size in http
+        fn expect_hex(self, name: SpecName, value_type: ValueType, optional: bool) ->  ProtoSpecBuilderData<BuildDelimiter<TextNumberSpec, IBS>>
+        where
+        ProtoSpecBuilderData<BuildDelimiter<TextNumberSpec, IBS>>:From<BuilderWrapperWithData<Self, TextNumberSpec, IBS>> + 'static,
+        {
+            self.wrap_with_data(TextNumberSpec::new(name, value_type, Radix::Hex, optional)).into()
+        }
+    }
+
+    impl TextNumberSpecBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch>{}
+
+    impl TextNumberSpecBuilder<BuildInlineValue, BuildFromScratch> for ProtoSpecBuilderData<BuildInlineValue>{}
+
+    impl TextNumberSpecBuilder<BuildValue, BuildFromScratch> for ProtoSpecBuilderData<BuildValue>{}
+
+    /// Trait that allows adding timestamps in text or binary form
+    pub trait TimestampSpecBuilder <IBS, OBS> : ProtoSpecBuilder<IBS>  
+    where 
+        Self: Sized + 'static,
+        IBS: BuilderState + 'static,
+        OBS:BuilderState + 'static, 
+    {
+        /// Expects timestamp written as text in `format` e.g TimestampFormat::ImfFixdate for http Date header
+        fn expect_timestamp(self, name: SpecName, format: TimestampFormat, optional: bool) ->  ProtoSpecBuilderData<BuildDelimiter<TextTimestampSpec, IBS>>
+        where
+        ProtoSpecBuilderData<BuildDelimiter<TextTimestampSpec, IBS>>:From<BuilderWrapperWithData<Self, TextTimestampSpec, IBS>> + 'static,
+        {
+            self.wrap_with_data(TextTimestampSpec::new(name, format, optional)).into()
+        }
+
+        /// Expects timestamp encoded as binary number of `unit`s from unix epoch. `word_type` is u32 or u64
+        fn expect_epoch_timestamp(self, name: SpecName, unit: EpochUnit, word_type: ValueType, endianness: Endianness, optional: bool) -> ProtoSpecBuilderData<OBS>
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, EpochTimestampSpec, IBS>> + 'static,
+        {
+            self.wrap_with_data(EpochTimestampSpec::new(name, unit, word_type, endianness, optional)).into()
+        }
+    }
+
+    impl TimestampSpecBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch>{}
+
+    impl TimestampSpecBuilder<BuildInlineValue, BuildFromScratch> for ProtoSpecBuilderData<BuildInlineValue>{}
+
+    impl TimestampSpecBuilder<BuildValue, BuildFromScratch> for ProtoSpecBuilderData<BuildValue>{}
+
+    /// Trait that allows adding network addresses in binary or text form
+    pub trait AddressSpecBuilder <IBS, OBS> : ProtoSpecBuilder<IBS>  
+    where 
+        Self: Sized + 'static,
+        IBS: BuilderState + 'static,
+        OBS:BuilderState + 'static, 
+    {
+        /// Expects binary address of `kind` e.g AddressKind::SocketV4 for destination of SOCKS5 request
+        fn expect_address(self, name: SpecName, kind: AddressKind, optional: bool) -> ProtoSpecBuilderData<OBS>
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, AddressSpec, IBS>> + 'static,
+        {
+            self.wrap_with_data(AddressSpec::new(name, kind, optional)).into()
+        }
+
+        /// Expects 4 bytes of IPv4 address e.g DNS A record
+        fn expect_ipv4(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS>
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, AddressSpec, IBS>> + 'static,
+        {
+            self.expect_address(name, AddressKind::Ipv4, optional)
+        }
+
+        /// Expects 16 bytes of IPv6 address e.g DNS AAAA record
+        fn expect_ipv6(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS>
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, AddressSpec, IBS>> + 'static,
+        {
+            self.expect_address(name, AddressKind::Ipv6, optional)
+        }
+
+        /// Expects 6 bytes of MAC address e.g client hardware address in DHCP
+        fn expect_mac(self, name: SpecName, optional: bool) -> ProtoSpecBuilderData<OBS>
+        where
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, AddressSpec, IBS>> + 'static,
+        {
+            self.expect_address(name, AddressKind::Mac, optional)
+        }
+
+        /// Expects address of `kind` written as text e.g source address in PROXY protocol header
+        fn expect_text_address(self, name: SpecName, kind: AddressKind, optional: bool) ->  ProtoSpecBuilderData<BuildDelimiter<TextAddressSpec, IBS>>
+        where
+        ProtoSpecBuilderData<BuildDelimiter<TextAddressSpec, IBS>>:From<BuilderWrapperWithData<Self, TextAddressSpec, IBS>> + 'static,
+        {
+            self.wrap_with_data(TextAddressSpec::new(name, kind, optional)).into()
+        }
+    }
+
+    impl AddressSpecBuilder<BuildFromScratch, BuildFromScratch> for ProtoSpecBuilderData<BuildFromScratch>{}
+
+    impl AddressSpecBuilder<BuildInlineValue, BuildFromScratch> for ProtoSpecBuilderData<BuildInlineValue>{}
+
+    impl AddressSpecBuilder<BuildValue, BuildFromScratch> for ProtoSpecBuilderData<BuildValue>{}
+
     /// Wrapper that contains another ProtoSpecBuilder and some arbitrary intermediate data
     pub struct BuilderWrapperWithData<B,D, BS>(B, D , PhantomData<BS> ) 
     where
@@ -4086,16 +8339,16 @@ pub mod builders{
 
      
 
-    impl <D> From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildKey>>, String , BuildDelimiter<D, BuildKey>>> for ProtoSpecBuilderData<BuildKeyAvailable> 
+    impl <D> From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildKey>>, Separator , BuildDelimiter<D, BuildKey>>> for ProtoSpecBuilderData<BuildKeyAvailable> 
     where 
         D:DelimitedSpec + StringSpec + ProtocolSpec + 'static,        
     {
-        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildKey>>, String, BuildDelimiter<D, BuildKey>>) -> Self 
+        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildKey>>, Separator, BuildDelimiter<D, BuildKey>>) -> Self 
         {
             let mut from_builder = value.0;            
             let from_state = from_builder.replace_current_state_with_default();
             let mut spec = from_state.delimiter_spec;
-            spec.set_delimiter(Separator::Delimiter(value.1));
+            spec.set_delimiter(value.1);
             let mut result = ProtoSpecBuilderData::default();
             let key = Key(Box::new(spec), from_state.parent_builder_state.key_spec_metadata);
             result.set_state(BuildKeyAvailable{
@@ -4106,16 +8359,16 @@ pub mod builders{
         }
     }
 
-    impl <D> From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildFromScratch>>, String , BuildDelimiter<D, BuildFromScratch>>> for ProtoSpecBuilderData<BuildFromScratch> 
+    impl <D> From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildFromScratch>>, Separator , BuildDelimiter<D, BuildFromScratch>>> for ProtoSpecBuilderData<BuildFromScratch> 
     where 
-        D:DelimitedSpec + StringSpec + ProtocolSpec + 'static,        
+        D:DelimitedSpec + ProtocolSpec + 'static,        
     {
-        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildFromScratch>>, String, BuildDelimiter<D, BuildFromScratch>>) -> Self 
+        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildFromScratch>>, Separator, BuildDelimiter<D, BuildFromScratch>>) -> Self 
         {
             let mut from_builder = value.0;            
             let from_state = from_builder.replace_current_state_with_default();
             let mut spec = from_state.delimiter_spec;
-            spec.set_delimiter(Separator::Delimiter(value.1));
+            spec.set_delimiter(value.1);
             let mut result = ProtoSpecBuilderData::default();
             let new_state = from_state.parent_builder_state;
             result.set_state(new_state);
@@ -4125,16 +8378,16 @@ pub mod builders{
         }
     }
 
-    impl <D> From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildKeyAvailable>>, String , BuildDelimiter<D, BuildKeyAvailable>>> for ProtoSpecBuilderData<BuildKeyAvailable> 
+    impl <D> From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildKeyAvailable>>, Separator , BuildDelimiter<D, BuildKeyAvailable>>> for ProtoSpecBuilderData<BuildKeyAvailable> 
     where 
         D:DelimitedSpec + StringSpec + ProtocolSpec + 'static,      
     {
-        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildKeyAvailable>>, String, BuildDelimiter<D, BuildKeyAvailable>>) -> Self 
+        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildKeyAvailable>>, Separator, BuildDelimiter<D, BuildKeyAvailable>>) -> Self 
         {
             let mut from_builder = value.0;            
             let from_state = from_builder.replace_current_state_with_default();
             let mut spec = from_state.delimiter_spec;
-            spec.set_delimiter(Separator::Delimiter(value.1));
+            spec.set_delimiter(value.1);
             let mut result = ProtoSpecBuilderData::default();
             let new_state = from_state.parent_builder_state;
             result.set_state(new_state);
@@ -4145,7 +8398,7 @@ pub mod builders{
     }
 
     impl <D> From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildKey>, D, BuildKey>> for ProtoSpecBuilderData<BuildKeyAvailable>
-     where D:StringSpec + ProtocolSpec + 'static{
+     where D:KeySpec + 'static{
         fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildKey>, D, BuildKey>) -> Self {
             let mut from_builder = value.0;
             let from_state = from_builder.replace_current_state_with_default();
@@ -4190,20 +8443,20 @@ pub mod builders{
         }
     }
              
-    impl <D> From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildValue>>, String, BuildDelimiter<D, BuildValue>>> for ProtoSpecBuilderData<BuildFromScratch>
+    impl <D> From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildValue>>, Separator, BuildDelimiter<D, BuildValue>>> for ProtoSpecBuilderData<BuildFromScratch>
     where D: DelimitedSpec + ProtocolSpec + 'static,
            // IBS: BuilderState + 'static,
      {
-        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildValue>>, String, BuildDelimiter<D, BuildValue>>) -> Self {
+        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildValue>>, Separator, BuildDelimiter<D, BuildValue>>) -> Self {
             let mut from_builder = value.0;
             let mut from_state = from_builder.replace_current_state_with_default();
             let mut result = ProtoSpecBuilderData::default();
             let optional = from_state.parent_builder_state.key.1.optional;
-            from_state.delimiter_spec.set_delimiter(Separator::Delimiter(value.1));
+            from_state.delimiter_spec.set_delimiter(value.1);
             let key_value = KeyValueSpec::new(
                 from_state.parent_builder_state.key,
                 ValueSpec(Box::new(from_state.delimiter_spec), from_state.parent_builder_state.value_spec_metadata),
-                SpecMetaData::new(SpecName::Name("key-value-spec".to_owned()), ValueType::None, optional),
+                SpecMetaData::new(SpecName::Transient("key-value-spec".to_owned()), ValueType::None, optional),
             );
             from_builder.add_spec(Box::new(key_value));
             result.set_state(BuildFromScratch{});
@@ -4212,15 +8465,15 @@ pub mod builders{
         }
     }
 
-    impl <D> From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildInlineValue>>, String, BuildDelimiter<D, BuildInlineValue>>> for ProtoSpecBuilderData<BuildFromScratch>
+    impl <D> From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildInlineValue>>, Separator, BuildDelimiter<D, BuildInlineValue>>> for ProtoSpecBuilderData<BuildFromScratch>
     where D: DelimitedSpec + ProtocolSpec + 'static,   
            // IBS: BuilderState + 'static,
      {
-        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildInlineValue>>, String, BuildDelimiter<D, BuildInlineValue>>) -> Self {
+        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildDelimiter<D, BuildInlineValue>>, Separator, BuildDelimiter<D, BuildInlineValue>>) -> Self {
             let mut from_builder = value.0;
             let mut from_state = from_builder.replace_current_state_with_default();
             let mut result = ProtoSpecBuilderData::default();            
-            from_state.delimiter_spec.set_delimiter(Separator::Delimiter(value.1));
+            from_state.delimiter_spec.set_delimiter(value.1);
             let inline_key_value = InlineKeyWithValue(Box::new(from_state.delimiter_spec), from_state.parent_builder_state.value_spec_metadata);
             from_builder.add_spec(Box::new(inline_key_value));
             result.set_state(BuildFromScratch{});
@@ -4229,24 +8482,8 @@ pub mod builders{
         }
     }
 
-    impl  From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildInlineValue>, ExactStringSpec, BuildInlineValue>>  for ProtoSpecBuilderData<BuildFromScratch>{
-        
-        fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildInlineValue>, ExactStringSpec, BuildInlineValue>) -> Self {
-            let mut from_builder = value.0;
-            let from_state = from_builder.replace_current_state_with_default();
-            let mut result = ProtoSpecBuilderData::default();            
-            let spec = value.1;
-            let inline_key_value = InlineKeyWithValue(Box::new(spec), from_state.value_spec_metadata);
-            from_builder.add_spec(Box::new(inline_key_value));
-            result.set_state(BuildFromScratch{});
-            result.set_spec(from_builder.build());    
-            result
-            
-        }
-    }
-
     impl <S>  From<BuilderWrapperWithData<ProtoSpecBuilderData<BuildInlineValue>, S, BuildInlineValue>>  for ProtoSpecBuilderData<BuildFromScratch>
-    where S:NumberSpec + 'static
+    where S:UndelimitedSpec + 'static
     {
         
         fn from(value: BuilderWrapperWithData<ProtoSpecBuilderData<BuildInlineValue>, S, BuildInlineValue>) -> Self {
@@ -4297,7 +8534,7 @@ pub mod builders{
         fn delimited_by_newline(self)-> ProtoSpecBuilderData<OBS>
         where 
             
-            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, String, BuildDelimiter<D, IBS>>>,
+            ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, Separator, BuildDelimiter<D, IBS>>>,
             
         {
             self.create_delimiter("\r\n".to_owned())
@@ -4306,22 +8543,41 @@ pub mod builders{
         
 
         fn delimited_by_space(self,)-> ProtoSpecBuilderData<OBS>
-        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, String, BuildDelimiter<D, IBS>>>,
+        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, Separator, BuildDelimiter<D, IBS>>>,
         {
             self.create_delimiter( " ".to_string())
         }
 
         fn delimited_by(self, delimiter: String)-> ProtoSpecBuilderData<OBS>
-        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, String, BuildDelimiter<D, IBS>>>,
+        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, Separator, BuildDelimiter<D, IBS>>>,
         {
             self.create_delimiter( delimiter)
         }
 
+        /// Delimited by any of the `delimiters` e.g "\r\n" or "\n" from lenient peers. Matched delimiter is written during
+        /// serialization of parsed data, otherwise the first delimiter is written
+        ///
+        /// # Panics
+        /// Panics if `delimiters` is empty
+        fn delimited_by_any_of(self, delimiters: Vec<String>)-> ProtoSpecBuilderData<OBS>
+        where ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, Separator, BuildDelimiter<D, IBS>>>,
+        {
+            assert!(!delimiters.is_empty(), "at least one delimiter is expected");
+            self.create_separator(Separator::AnyOf(delimiters))
+        }
+
         fn create_delimiter(self, delimiter: String,) -> ProtoSpecBuilderData<OBS>
         where 
-        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, String, BuildDelimiter<D, IBS>>>,
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, Separator, BuildDelimiter<D, IBS>>>,
         {
-            let r: BuilderWrapperWithData<Self, String, BuildDelimiter<D, IBS>> = self.wrap_with_data(delimiter);
+            self.create_separator(Separator::Delimiter(delimiter))
+        }
+
+        fn create_separator(self, separator: Separator,) -> ProtoSpecBuilderData<OBS>
+        where 
+        ProtoSpecBuilderData<OBS>: From<BuilderWrapperWithData<Self, Separator, BuildDelimiter<D, IBS>>>,
+        {
+            let r: BuilderWrapperWithData<Self, Separator, BuildDelimiter<D, IBS>> = self.wrap_with_data(separator);
             r.into()
         }
     }
@@ -4337,12 +8593,28 @@ pub mod builders{
          OBS: BuilderState + 'static,
    {}
 
+    impl <D, IBS> ProtoSpecBuilderData<BuildDelimiter<D, IBS>>
+    where D: DelimitedSpec + 'static,
+          IBS: BuilderState + 'static,
+    {
+        /// Validates the value using `validator` during deserialization and serialization e.g CharClass::Digits
+        pub fn validated_by<V>(mut self, validator: V) -> Self
+        where V: ValueValidator + 'static,
+        {
+            self.state.delimiter_spec.set_validator(Box::new(validator));
+            self
+        }
+    }
+
    
 
     
 }
     pub(crate) mod protocol_reader;
     mod protocol_writer;
+    pub(crate) mod serde_bridge;
+    #[cfg(test)]
+    mod tests;
 }
 
 mod utils;
@@ -4368,7 +8640,7 @@ mod test_utils {
 
     use tracing::warn;
 
-    use crate::{core::{InfoProvider, RequestInfo}, mapping_extractor::DefaultMapper};
+    use crate::{core::{DefaultSerializer, InfoProvider, ListSpec, ParserError, RequestInfo, RequestSerializer}, mapping_extractor::{DefaultMapper, SpecTraverse}};
 
     pub fn assert_result_has_string(
         result: Result<Option<Vec<u8>>, crate::core::ParserError>,
@@ -4403,6 +8675,25 @@ mod test_utils {
         }
     }
 
+    /// Returns a request info whose mapper has been initialized from `spec`
+    pub fn new_request_info(spec: &ListSpec) -> TestRequestInfo {
+        let mut mapper = DefaultMapper::new();
+        assert!(spec.traverse(&mut mapper).is_ok());
+        TestRequestInfo(mapper, Vec::new())
+    }
+
+    /// Parses `data` with `spec` and stores the parsed values in `request_info`
+    pub async fn deserialize_with(request_info: &mut TestRequestInfo, data: &[u8], spec: &ListSpec) -> Result<(), ParserError> {
+        DefaultSerializer{}.deserialize_from(request_info, data, spec).await.map(|_| ())
+    }
+
+    /// Writes the values of `request_info` with `spec` and returns the written bytes
+    pub async fn serialize_with(request_info: &mut TestRequestInfo, spec: ListSpec) -> Result<Vec<u8>, ParserError> {
+        let mut buffer = Vec::new();
+        DefaultSerializer{}.serialize_to(request_info, &mut buffer, Box::new(spec)).await?;
+        Ok(buffer)
+    }
+
     impl RequestInfo for TestRequestInfo{}
 
     impl InfoProvider for TestRequestInfo {